@@ -0,0 +1,264 @@
+use super::{parse_rooms, password, Direction, Room, Ship};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+
+pub const CHECKPOINT: &str = "Security Checkpoint";
+
+pub struct Agent {
+    ship: Ship,
+    current: String,
+    rooms: HashMap<String, Room>,
+    links: HashMap<(String, Direction), String>,
+    inventory: Vec<String>,
+    dangerous: Vec<String>,
+    floor: Option<Direction>,
+    commands: Vec<String>,
+    password: Option<String>,
+}
+
+impl Agent {
+    pub fn new(program: &[i64]) -> Result<Agent, Box<dyn Error>> {
+        let mut ship = Ship::new(program);
+        let output = ship.run(&[])?;
+
+        let room = parse_rooms(&output)
+            .pop()
+            .ok_or("The droid did not describe its surroundings")?;
+
+        let mut agent = Agent {
+            ship,
+            current: room.name.clone(),
+            rooms: HashMap::new(),
+            links: HashMap::new(),
+            inventory: Vec::new(),
+            dangerous: Vec::new(),
+            floor: None,
+            commands: Vec::new(),
+            password: None,
+        };
+
+        agent.rooms.insert(room.name.clone(), room);
+
+        Ok(agent)
+    }
+
+    pub fn rooms(&self) -> impl Iterator<Item = &Room> {
+        self.rooms.values()
+    }
+
+    pub fn inventory(&self) -> &[String] {
+        &self.inventory
+    }
+
+    pub fn dangerous(&self) -> &[String] {
+        &self.dangerous
+    }
+
+    pub fn commands(&self) -> &[String] {
+        &self.commands
+    }
+
+    fn send(&mut self, command: &str) -> Result<String, Box<dyn Error>> {
+        self.commands.push(command.to_string());
+        let output = self.ship.send(command)?;
+
+        if let Some(password) = password(&output) {
+            self.password = Some(password);
+        }
+
+        Ok(output)
+    }
+
+    fn walk(&mut self, door: Direction) -> Result<Vec<Room>, Box<dyn Error>> {
+        let rooms = parse_rooms(&self.send(&door.to_string())?);
+        let arrived = rooms.last().ok_or("The droid got lost")?;
+
+        self.current = arrived.name.clone();
+
+        Ok(rooms)
+    }
+
+    fn is_safe(&self, room: &Room, item: &str) -> bool {
+        let mut fork = self.ship.clone();
+
+        let taken = match fork.send(&format!("take {}", item)) {
+            Ok(output) => !fork.is_halted() && output.contains("You take the"),
+            Err(_) => false,
+        };
+
+        let door = match room.doors.first() {
+            Some(door) => *door,
+            None => return taken,
+        };
+
+        taken
+            && match fork.send(&door.to_string()) {
+                Ok(output) => !fork.is_halted() && !parse_rooms(&output).is_empty(),
+                Err(_) => false,
+            }
+    }
+
+    fn collect(&mut self, room: &Room) -> Result<(), Box<dyn Error>> {
+        for item in &room.items {
+            if self.is_safe(room, item) {
+                self.send(&format!("take {}", item))?;
+                self.inventory.push(item.clone());
+            } else {
+                self.dangerous.push(item.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit(&mut self, room: Room) -> Result<(), Box<dyn Error>> {
+        self.collect(&room)?;
+        self.rooms.insert(room.name.clone(), room.clone());
+
+        for door in &room.doors {
+            if self.links.contains_key(&(room.name.clone(), *door)) || self.ship.is_halted() {
+                continue;
+            }
+
+            let rooms = self.walk(*door)?;
+            let arrived = rooms.last().cloned().ok_or("The droid got lost")?;
+
+            if arrived.name == room.name {
+                self.floor = Some(*door);
+                self.links
+                    .insert((room.name.clone(), *door), rooms[0].name.clone());
+                continue;
+            }
+
+            self.links
+                .insert((room.name.clone(), *door), arrived.name.clone());
+            self.links
+                .insert((arrived.name.clone(), door.reverse()), room.name.clone());
+
+            if !self.rooms.contains_key(&arrived.name) {
+                self.visit(arrived)?;
+            }
+
+            if self.ship.is_halted() {
+                return Ok(());
+            }
+
+            self.walk(door.reverse())?;
+        }
+
+        Ok(())
+    }
+
+    pub fn explore(&mut self) -> Result<(), Box<dyn Error>> {
+        let room = self.rooms[&self.current].clone();
+        self.rooms.clear();
+        self.visit(room)
+    }
+
+    fn path(&self, to: &str) -> Option<Vec<Direction>> {
+        let mut came_from: HashMap<String, (String, Direction)> = HashMap::new();
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+
+        visited.insert(self.current.clone());
+        queue.push_back(self.current.clone());
+
+        while let Some(room) = queue.pop_front() {
+            if room == to {
+                let mut path = Vec::new();
+                let mut current = room;
+
+                while let Some((previous, door)) = came_from.get(&current) {
+                    path.push(*door);
+                    current = previous.clone();
+                }
+
+                path.reverse();
+                return Some(path);
+            }
+
+            for door in &self.rooms.get(&room)?.doors {
+                if let Some(next) = self.links.get(&(room.clone(), *door)) {
+                    if self.rooms.contains_key(next) && visited.insert(next.clone()) {
+                        came_from.insert(next.clone(), (room.clone(), *door));
+                        queue.push_back(next.clone());
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn solve(&mut self) -> Result<String, Box<dyn Error>> {
+        self.explore()?;
+
+        if let Some(password) = &self.password {
+            return Ok(password.clone());
+        }
+
+        let path = self
+            .path(CHECKPOINT)
+            .ok_or("No path to the security checkpoint")?;
+
+        for door in path {
+            self.walk(door)?;
+        }
+
+        let floor = self
+            .floor
+            .ok_or("The pressure-sensitive floor was not found")?;
+        let items = self.inventory.clone();
+        let mut held = vec![true; items.len()];
+
+        for attempt in 0u64..1 << items.len() {
+            if attempt > 0 {
+                let item = attempt.trailing_zeros() as usize;
+                let command = if held[item] { "drop" } else { "take" };
+
+                self.send(&format!("{} {}", command, items[item]))?;
+                held[item] = !held[item];
+            }
+
+            self.walk(floor)?;
+
+            if let Some(password) = &self.password {
+                self.inventory = items
+                    .iter()
+                    .zip(&held)
+                    .filter(|(_, held)| **held)
+                    .map(|(item, _)| item.clone())
+                    .collect();
+
+                return Ok(password.clone());
+            }
+        }
+
+        Err("No combination of items passes the pressure-sensitive floor".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Agent;
+    use std::error::Error;
+
+    fn program() -> Vec<i64> {
+        include_str!("../../day_25_a/input")
+            .split(',')
+            .map(|v| v.trim().parse::<i64>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_solve() -> Result<(), Box<dyn Error>> {
+        let mut agent = Agent::new(&program())?;
+        let password = agent.solve()?;
+
+        assert_eq!(password, "8401920");
+        assert!(agent.rooms().any(|room| room.name == super::CHECKPOINT));
+        assert!(!agent.dangerous().is_empty());
+
+        Ok(())
+    }
+}
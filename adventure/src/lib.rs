@@ -0,0 +1,239 @@
+use int_comp::{IntcodeComputer, IntcodeOutput};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+pub mod agent;
+
+pub const FUEL: u64 = 1_000_000;
+pub const MAX_OUTPUT: usize = 10_000;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    pub fn reverse(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Direction::North => write!(f, "north"),
+            Direction::South => write!(f, "south"),
+            Direction::East => write!(f, "east"),
+            Direction::West => write!(f, "west"),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "north" => Ok(Direction::North),
+            "south" => Ok(Direction::South),
+            "east" => Ok(Direction::East),
+            "west" => Ok(Direction::West),
+            _ => Err(format!("Unknown direction: {}", input).into()),
+        }
+    }
+}
+
+#[derive(Clone, Default, Eq, PartialEq, Debug)]
+pub struct Room {
+    pub name: String,
+    pub description: String,
+    pub doors: Vec<Direction>,
+    pub items: Vec<String>,
+}
+
+enum Section {
+    Description,
+    Doors,
+    Items,
+    Other,
+}
+
+pub fn parse_rooms(output: &str) -> Vec<Room> {
+    let mut rooms: Vec<Room> = Vec::new();
+    let mut section = Section::Other;
+
+    for line in output.lines().map(|line| line.trim()) {
+        if let Some(name) = line.strip_prefix("== ").and_then(|l| l.strip_suffix(" ==")) {
+            rooms.push(Room {
+                name: name.to_string(),
+                ..Room::default()
+            });
+            section = Section::Description;
+            continue;
+        }
+
+        let room = match rooms.last_mut() {
+            Some(room) => room,
+            None => continue,
+        };
+
+        match line {
+            "" => section = Section::Other,
+            "Doors here lead:" => section = Section::Doors,
+            "Items here:" => section = Section::Items,
+            _ => match (&section, line.strip_prefix("- ")) {
+                (Section::Doors, Some(door)) => {
+                    if let Ok(door) = door.parse() {
+                        room.doors.push(door);
+                    }
+                }
+                (Section::Items, Some(item)) => room.items.push(item.to_string()),
+                (Section::Description, _) => {
+                    if !room.description.is_empty() {
+                        room.description.push('\n');
+                    }
+                    room.description.push_str(line);
+                }
+                _ => {}
+            },
+        }
+    }
+
+    rooms
+}
+
+pub fn password(output: &str) -> Option<String> {
+    let start = output.find("typing ")? + "typing ".len();
+
+    let password = output[start..]
+        .chars()
+        .take_while(|ch| ch.is_ascii_digit())
+        .collect::<String>();
+
+    if password.is_empty() {
+        None
+    } else {
+        Some(password)
+    }
+}
+
+#[derive(Clone)]
+pub struct Ship {
+    int_comp: IntcodeComputer,
+    halted: bool,
+}
+
+impl Ship {
+    pub fn new(program: &[i64]) -> Ship {
+        Ship {
+            int_comp: IntcodeComputer::new(program),
+            halted: false,
+        }
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    pub fn run(&mut self, input: &[i64]) -> Result<String, Box<dyn Error>> {
+        if self.halted {
+            return Err("The droid is no longer responding".into());
+        }
+
+        let mut output = String::new();
+        let mut input = input;
+
+        while output.len() < MAX_OUTPUT {
+            match self.int_comp.run_with_fuel(input, Some(1), FUEL)? {
+                IntcodeOutput::Interrupt(data) => {
+                    output.extend(data.iter().map(|c| *c as u8 as char));
+                    input = &[];
+                }
+                IntcodeOutput::Halt(data) => {
+                    output.extend(data.iter().map(|c| *c as u8 as char));
+                    self.halted = true;
+                    return Ok(output);
+                }
+                IntcodeOutput::NeedMoreInput => return Ok(output),
+            }
+        }
+
+        Err("The droid is not done talking".into())
+    }
+
+    pub fn send(&mut self, command: &str) -> Result<String, Box<dyn Error>> {
+        let input = command
+            .chars()
+            .chain(std::iter::once('\n'))
+            .map(|ch| ch as i64)
+            .collect::<Vec<_>>();
+
+        self.run(&input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_rooms, password, Direction};
+
+    const OUTPUT: &str = "
+
+
+== Pressure-Sensitive Floor ==
+Analyzing...
+
+Doors here lead:
+- east
+
+A loud, robotic voice says \"Alert! Droids on this ship are heavier than the detected value!\" and you are ejected back to the checkpoint.
+
+
+
+== Security Checkpoint ==
+In the next room, a pressure-sensitive floor will verify your identity.
+
+Doors here lead:
+- north
+- west
+
+Items here:
+- mutex
+- giant electromagnet
+
+Command?
+";
+
+    #[test]
+    fn test_parse_rooms() {
+        let rooms = parse_rooms(OUTPUT);
+
+        assert_eq!(rooms.len(), 2);
+        assert_eq!(rooms[0].name, "Pressure-Sensitive Floor");
+        assert_eq!(rooms[0].doors, [Direction::East]);
+        assert_eq!(rooms[1].name, "Security Checkpoint");
+        assert_eq!(
+            rooms[1].description,
+            "In the next room, a pressure-sensitive floor will verify your identity."
+        );
+        assert_eq!(rooms[1].doors, [Direction::North, Direction::West]);
+        assert_eq!(rooms[1].items, ["mutex", "giant electromagnet"]);
+    }
+
+    #[test]
+    fn test_password() {
+        assert_eq!(password(OUTPUT), None);
+        assert_eq!(
+            password("You should be able to get in by typing 2147485856 on the keypad"),
+            Some("2147485856".to_string())
+        );
+    }
+}
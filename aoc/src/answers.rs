@@ -0,0 +1,293 @@
+use super::{Outcome, Part};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Clone, Default, Debug)]
+pub struct Answers {
+    expected: HashMap<(u32, Part), String>,
+}
+
+impl Answers {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Answers, Box<dyn Error>> {
+        let path = path.as_ref();
+
+        fs::read_to_string(path)
+            .map_err(|err| format!("Could not read answers {}: {}", path.display(), err))?
+            .parse()
+    }
+
+    pub fn expected(&self, day: u32, part: Part) -> Option<&str> {
+        self.expected
+            .get(&(day, part))
+            .map(|answer| answer.as_str())
+    }
+
+    pub fn verify(&self, outcome: &Outcome) -> Verdict {
+        match (self.expected(outcome.day, outcome.part), &outcome.answer) {
+            (_, Err(_)) => Verdict::Fail,
+            (None, Ok(_)) => Verdict::Unknown,
+            (Some(expected), Ok(answer)) if answer.value == expected => Verdict::Pass,
+            (Some(_), Ok(_)) => Verdict::Fail,
+        }
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (index, ch) in line.char_indices() {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..index],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+fn parse_string(string: &str) -> Result<String, Box<dyn Error>> {
+    let mut value = String::new();
+    let mut chars = string.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if chars.as_str().is_empty() => return Ok(value),
+            '"' => return Err(format!("Unexpected text after string: {}", chars.as_str()).into()),
+            '\\' => value.push(match chars.next() {
+                Some('"') => '"',
+                Some('\\') => '\\',
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some(ch) => return Err(format!("Unsupported escape: \\{}", ch).into()),
+                None => break,
+            }),
+            ch => value.push(ch),
+        }
+    }
+
+    Err("Unterminated string".into())
+}
+
+fn parse_value(value: &str) -> Result<String, Box<dyn Error>> {
+    let value = value.trim();
+
+    if let Some(string) = value.strip_prefix('"') {
+        parse_string(string)
+    } else if !value.is_empty() && value.chars().all(|ch| ch.is_ascii_digit() || ch == '-') {
+        Ok(value.to_string())
+    } else {
+        Err(format!("Unsupported value: {}", value).into())
+    }
+}
+
+// Only the TOML subset used by answers.toml: [[answer]] tables of key = value lines.
+impl FromStr for Answers {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut tables: Vec<HashMap<String, String>> = Vec::new();
+
+        for (number, line) in input.lines().enumerate() {
+            let line = strip_comment(line).trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if line == "[[answer]]" {
+                tables.push(HashMap::new());
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("Line {}: expected key = value", number + 1))?;
+            let table = tables
+                .last_mut()
+                .ok_or_else(|| format!("Line {}: key outside of [[answer]]", number + 1))?;
+            let value =
+                parse_value(value).map_err(|err| format!("Line {}: {}", number + 1, err))?;
+
+            table.insert(key.trim().to_string(), value);
+        }
+
+        let mut expected = HashMap::new();
+
+        for table in tables {
+            let field = |key: &str| {
+                table
+                    .get(key)
+                    .ok_or_else(|| format!("Answer is missing `{}`", key))
+            };
+
+            let day = field("day")?.parse::<u32>()?;
+            let part = field("part")?.parse::<Part>()?;
+
+            if expected
+                .insert((day, part), field("expected")?.clone())
+                .is_some()
+            {
+                return Err(format!("Duplicate answer for day {} {}", day, part).into());
+            }
+        }
+
+        Ok(Answers { expected })
+    }
+}
+
+pub fn answers_path() -> PathBuf {
+    input::workspace_dir().join("answers.toml")
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => f.pad("PASS"),
+            Verdict::Fail => f.pad("FAIL"),
+            Verdict::Unknown => f.pad("UNKNOWN"),
+        }
+    }
+}
+
+pub fn report(outcomes: &[Outcome], answers: &Answers) -> String {
+    let mut report = String::new();
+    let mut counts = [0; 3];
+
+    for outcome in outcomes {
+        let verdict = answers.verify(outcome);
+        let answer = match &outcome.answer {
+            Ok(answer) => answer.value.clone(),
+            Err(err) => format!("error: {}", err),
+        };
+
+        report.push_str(&format!(
+            "{:>3} {:>4}  {:<7}  {}",
+            outcome.day, outcome.part, verdict, answer
+        ));
+
+        if verdict == Verdict::Fail {
+            if let Some(expected) = answers.expected(outcome.day, outcome.part) {
+                report.push_str(&format!(" (expected {})", expected));
+            }
+        }
+
+        report.push('\n');
+        counts[verdict as usize] += 1;
+    }
+
+    report.push_str(&format!(
+        "PASS: {}, FAIL: {}, UNKNOWN: {}\n",
+        counts[0], counts[1], counts[2]
+    ));
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{report, Answers, Verdict};
+    use crate::{Answer, Outcome, Part};
+    use std::time::Duration;
+
+    const ANSWERS: &str = "
+# Known answers
+[[answer]]
+day = 1
+part = \"a\"
+expected = 42
+
+[[answer]]
+day = 8
+part = \"b\"
+expected = \"PZEKB\" # letters
+";
+
+    fn outcome(day: u32, part: Part, answer: Result<&str, &str>) -> Outcome {
+        Outcome {
+            day,
+            part,
+            answer: answer.map(Answer::new).map_err(|err| err.to_string()),
+            elapsed: Duration::default(),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = ANSWERS.parse::<Answers>().unwrap();
+
+        assert_eq!(answers.expected(1, Part::A), Some("42"));
+        assert_eq!(answers.expected(8, Part::B), Some("PZEKB"));
+        assert_eq!(answers.expected(1, Part::B), None);
+
+        assert!("day = 1".parse::<Answers>().is_err());
+        assert!("[[answer]]\nday = 1\npart = \"a\""
+            .parse::<Answers>()
+            .is_err());
+        assert!("[[answer]]\nday = 1\npart = \"c\"\nexpected = 1"
+            .parse::<Answers>()
+            .is_err());
+        let with_expected = |value: &str| {
+            format!("[[answer]]\nday = 3\npart = \"a\"\nexpected = {}", value).parse::<Answers>()
+        };
+
+        let answers = with_expected("\"a#\\\"b\\\\\" # c").unwrap();
+        assert_eq!(answers.expected(3, Part::A), Some("a#\"b\\"));
+
+        assert!(with_expected("\"a").is_err());
+        assert!(with_expected("\"a\" b").is_err());
+        assert!(with_expected("\"\\x\"").is_err());
+        assert!(format!("{}{}", ANSWERS, ANSWERS)
+            .parse::<Answers>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = ANSWERS.parse::<Answers>().unwrap();
+
+        let outcomes = [
+            outcome(1, Part::A, Ok("42")),
+            outcome(8, Part::B, Ok("PZEKR")),
+            outcome(1, Part::B, Ok("7")),
+            outcome(8, Part::B, Err("Broken")),
+            outcome(2, Part::A, Err("Broken")),
+        ];
+
+        let verdicts = outcomes
+            .iter()
+            .map(|outcome| answers.verify(outcome))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            verdicts,
+            [
+                Verdict::Pass,
+                Verdict::Fail,
+                Verdict::Unknown,
+                Verdict::Fail,
+                Verdict::Fail
+            ]
+        );
+
+        let report = report(&outcomes, &answers);
+        let lines = report.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[1], "  8    b  FAIL     PZEKR (expected PZEKB)");
+        assert_eq!(lines[5], "PASS: 1, FAIL: 3, UNKNOWN: 1");
+    }
+}
@@ -0,0 +1,97 @@
+use super::{Answer, Part, Puzzle, Solution};
+
+pub fn solutions() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new(Puzzle::new(1, Part::A, day_1_a::solve)),
+        Box::new(Puzzle::new(1, Part::B, day_1_b::solve)),
+        Box::new(Puzzle::new(2, Part::A, day_2_a::solve)),
+        Box::new(Puzzle::new(2, Part::B, day_2_b::solve)),
+        Box::new(Puzzle::new(3, Part::A, day_3_a::solve)),
+        Box::new(Puzzle::new(3, Part::B, day_3_b::solve)),
+        Box::new(Puzzle::new(4, Part::A, day_4_a::solve)),
+        Box::new(Puzzle::new(4, Part::B, day_4_b::solve)),
+        Box::new(Puzzle::new(5, Part::A, day_5_a::solve)),
+        Box::new(Puzzle::new(5, Part::B, day_5_b::solve)),
+        Box::new(Puzzle::new(6, Part::A, day_6_a::solve)),
+        Box::new(Puzzle::new(6, Part::B, day_6_b::solve)),
+        Box::new(Puzzle::new(7, Part::A, day_7_a::solve)),
+        Box::new(Puzzle::new(7, Part::B, day_7_b::solve)),
+        Box::new(Puzzle::new(8, Part::A, day_8_a::solve)),
+        Box::new(Puzzle::new(8, Part::B, day_8_b::solve)),
+        Box::new(Puzzle::new(9, Part::A, day_9_a::solve)),
+        Box::new(Puzzle::new(9, Part::B, day_9_b::solve)),
+        Box::new(Puzzle::detailed(10, Part::A, |input| {
+            let (pos, count) = day_10_a::monitoring_station(input);
+            Ok(Answer::new(count).with_diagnostics(format!("station at {},{}", pos[0], pos[1])))
+        })),
+        Box::new(Puzzle::new(10, Part::B, day_10_b::solve)),
+        Box::new(Puzzle::new(11, Part::A, day_11_a::solve)),
+        Box::new(Puzzle::new(11, Part::B, day_11_b::solve)),
+        Box::new(Puzzle::new(12, Part::A, day_12_a::solve)),
+        Box::new(Puzzle::new(12, Part::B, day_12_b::solve)),
+        Box::new(Puzzle::new(13, Part::A, day_13_a::solve)),
+        Box::new(
+            Puzzle::new(13, Part::B, day_13_b::solve).interactive(|input| {
+                let report = day_13_b::play(input, &mut day_13_b::Human, false)?;
+                println!("{}", report);
+                Ok(())
+            }),
+        ),
+        Box::new(Puzzle::new(14, Part::A, day_14_a::solve)),
+        Box::new(Puzzle::new(14, Part::B, day_14_b::solve)),
+        Box::new(Puzzle::new(15, Part::A, day_15_a::solve).interactive(day_15_a::play)),
+        Box::new(
+            Puzzle::new(15, Part::B, day_15_b::solve)
+                .interactive(|input| day_15_b::play(input, None)),
+        ),
+        Box::new(Puzzle::new(16, Part::A, day_16_a::solve)),
+        Box::new(Puzzle::new(16, Part::B, day_16_b::solve)),
+        Box::new(Puzzle::new(17, Part::A, day_17_a::solve)),
+        Box::new(Puzzle::new(17, Part::B, day_17_b::solve)),
+        Box::new(Puzzle::new(18, Part::A, day_18_a::solve)),
+        Box::new(Puzzle::new(18, Part::B, day_18_b::solve)),
+        Box::new(Puzzle::new(19, Part::A, day_19_a::solve)),
+        Box::new(Puzzle::new(19, Part::B, day_19_b::solve)),
+        Box::new(Puzzle::new(20, Part::A, day_20_a::solve)),
+        Box::new(Puzzle::new(20, Part::B, day_20_b::solve)),
+        Box::new(Puzzle::new(21, Part::A, day_21_a::solve)),
+        Box::new(Puzzle::new(21, Part::B, day_21_b::solve)),
+        Box::new(Puzzle::new(22, Part::A, day_22_a::solve)),
+        Box::new(Puzzle::new(22, Part::B, day_22_b::solve)),
+        Box::new(Puzzle::detailed(23, Part::A, |input| {
+            let network = day_23_a::simulate(input)?;
+            let packet = network.first_nat_packet().ok_or("No packet sent to 255")?;
+
+            Ok(Answer::new(packet.y).with_diagnostics(format!(
+                "rounds: {}, events: {}",
+                network.rounds(),
+                network.events().len()
+            )))
+        })),
+        Box::new(Puzzle::detailed(23, Part::B, |input| {
+            let network = day_23_b::simulate(input)?;
+            let y = network
+                .first_repeated_wakeup()
+                .ok_or("NAT never repeated")?;
+
+            Ok(Answer::new(y).with_diagnostics(format!(
+                "rounds: {} ({} idle), wakeups: {}",
+                network.rounds(),
+                network.idle_rounds(),
+                network.wakeups().count()
+            )))
+        })),
+        Box::new(Puzzle::new(24, Part::A, day_24_a::solve)),
+        Box::new(Puzzle::new(24, Part::B, day_24_b::solve)),
+        Box::new(
+            Puzzle::new(25, Part::A, day_25_a::solve)
+                .interactive(|input| day_25_a::play(input, None).map(|_| ())),
+        ),
+        Box::new(Puzzle::detailed(25, Part::B, |input| {
+            let (agent, password) = day_25_b::solve_agent(input)?;
+
+            Ok(Answer::new(password)
+                .with_diagnostics(format!("carried: {}", agent.inventory().join(", "))))
+        })),
+    ]
+}
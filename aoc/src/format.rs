@@ -0,0 +1,252 @@
+use super::Outcome;
+use std::error::Error;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown format: {}", input).into()),
+        }
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+
+    if micros < 1000.0 {
+        format!("{:.1} µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.1} ms", micros / 1000.0)
+    } else {
+        format!("{:.2} s", micros / 1_000_000.0)
+    }
+}
+
+pub fn table(outcomes: &[Outcome]) -> String {
+    let answers = outcomes
+        .iter()
+        .map(|outcome| match &outcome.answer {
+            Ok(answer) => answer.value.clone(),
+            Err(err) => format!("error: {}", err),
+        })
+        .collect::<Vec<_>>();
+
+    let width = answers
+        .iter()
+        .map(|answer| answer.chars().count())
+        .chain(std::iter::once("Answer".len()))
+        .max()
+        .unwrap_or(0);
+
+    let mut table = format!(
+        "{:>3} {:>4}  {:<width$}  {:>10}\n",
+        "Day",
+        "Part",
+        "Answer",
+        "Time",
+        width = width
+    );
+
+    for (outcome, answer) in outcomes.iter().zip(&answers) {
+        table.push_str(&format!(
+            "{:>3} {:>4}  {:<width$}  {:>10}\n",
+            outcome.day,
+            outcome.part,
+            answer,
+            format_duration(outcome.elapsed),
+            width = width
+        ));
+
+        if let Ok(Some(diagnostics)) = outcome.answer.as_ref().map(|a| &a.diagnostics) {
+            for line in diagnostics.lines() {
+                table.push_str(&format!("{:10}{}\n", "", line));
+            }
+        }
+    }
+
+    let total = outcomes
+        .iter()
+        .map(|outcome| outcome.elapsed)
+        .sum::<Duration>();
+    table.push_str(&format!("Total: {}\n", format_duration(total)));
+
+    table
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::from("\"");
+
+    for ch in value.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => json.push(ch),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+fn json_option(value: Option<&str>) -> String {
+    value.map(json_string).unwrap_or_else(|| "null".to_string())
+}
+
+pub fn to_json(outcomes: &[Outcome]) -> String {
+    let objects = outcomes
+        .iter()
+        .map(|outcome| {
+            let answer = outcome.answer.as_ref().ok();
+
+            format!(
+                "  {{\"day\":{},\"part\":\"{}\",\"answer\":{},\"diagnostics\":{},\"error\":{},\"elapsed_us\":{}}}",
+                outcome.day,
+                outcome.part,
+                json_option(answer.map(|answer| answer.value.as_str())),
+                json_option(answer.and_then(|answer| answer.diagnostics.as_deref())),
+                json_option(outcome.answer.as_ref().err().map(|err| err.as_str())),
+                outcome.elapsed.as_micros()
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if objects.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn to_csv(outcomes: &[Outcome]) -> String {
+    let mut csv = String::from("day,part,answer,diagnostics,error,elapsed_us\n");
+
+    for outcome in outcomes {
+        let answer = outcome.answer.as_ref().ok();
+
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            outcome.day,
+            outcome.part,
+            csv_field(answer.map(|answer| answer.value.as_str()).unwrap_or("")),
+            csv_field(
+                answer
+                    .and_then(|answer| answer.diagnostics.as_deref())
+                    .unwrap_or("")
+            ),
+            csv_field(outcome.answer.as_ref().err().map_or("", |err| err.as_str())),
+            outcome.elapsed.as_micros()
+        ));
+    }
+
+    csv
+}
+
+pub fn render(outcomes: &[Outcome], format: Format) -> String {
+    match format {
+        Format::Text => table(outcomes),
+        Format::Json => to_json(outcomes),
+        Format::Csv => to_csv(outcomes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render, Format};
+    use crate::{Answer, Outcome, Part};
+    use std::time::Duration;
+
+    fn outcomes() -> Vec<Outcome> {
+        vec![
+            Outcome {
+                day: 1,
+                part: Part::A,
+                answer: Ok(Answer::new(42)),
+                elapsed: Duration::from_micros(1500),
+            },
+            Outcome {
+                day: 10,
+                part: Part::B,
+                answer: Ok(Answer::new("404").with_diagnostics("station at 20,19")),
+                elapsed: Duration::from_micros(20),
+            },
+            Outcome {
+                day: 2,
+                part: Part::A,
+                answer: Err("Bad \"input\", line 1".to_string()),
+                elapsed: Duration::from_micros(3),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!("json".parse::<Format>().unwrap(), Format::Json);
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_text() {
+        let text = render(&outcomes(), Format::Text);
+        let lines = text.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 6);
+        assert!(lines[1].starts_with("  1    a  42 "));
+        assert!(lines[1].ends_with("1.5 ms"));
+        assert_eq!(lines[3].trim(), "station at 20,19");
+        assert!(lines[4].contains("error: Bad \"input\", line 1"));
+        assert!(lines[5].starts_with("Total: "));
+    }
+
+    #[test]
+    fn test_json() {
+        let json = render(&outcomes(), Format::Json);
+        let lines = json.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            lines[1],
+            "  {\"day\":1,\"part\":\"a\",\"answer\":\"42\",\"diagnostics\":null,\"error\":null,\"elapsed_us\":1500},"
+        );
+        assert!(lines[2].contains("\"diagnostics\":\"station at 20,19\""));
+        assert!(lines[3].contains(
+            "\"answer\":null,\"diagnostics\":null,\"error\":\"Bad \\\"input\\\", line 1\""
+        ));
+        assert_eq!(render(&[], Format::Json), "[]\n");
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = render(&outcomes(), Format::Csv);
+        let lines = csv.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[0], "day,part,answer,diagnostics,error,elapsed_us");
+        assert_eq!(lines[1], "1,a,42,,,1500");
+        assert_eq!(lines[2], "10,b,404,\"station at 20,19\",,20");
+        assert_eq!(lines[3], "2,a,,,\"Bad \"\"input\"\", line 1\",3");
+    }
+}
@@ -0,0 +1,216 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+pub mod answers;
+pub mod days;
+pub mod format;
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub fn letter(self) -> char {
+        match self {
+            Part::A => 'a',
+            Part::B => 'b',
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::A => f.pad("a"),
+            Part::B => f.pad("b"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "a" | "A" | "1" => Ok(Part::A),
+            "b" | "B" | "2" => Ok(Part::B),
+            _ => Err(format!("Unknown part: {}", input).into()),
+        }
+    }
+}
+
+pub trait Solution {
+    fn day(&self) -> u32;
+    fn part(&self) -> Part;
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn Error>>;
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+
+    fn play(&self, _input: &str) -> Result<(), Box<dyn Error>> {
+        Err(format!("Day {} {} is not interactive", self.day(), self.part()).into())
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Answer {
+    pub value: String,
+    pub diagnostics: Option<String>,
+}
+
+impl Answer {
+    pub fn new<T: ToString>(value: T) -> Answer {
+        Answer {
+            value: value.to_string(),
+            diagnostics: None,
+        }
+    }
+
+    pub fn with_diagnostics<T: ToString>(mut self, diagnostics: T) -> Answer {
+        self.diagnostics = Some(diagnostics.to_string());
+        self
+    }
+}
+
+pub type Solver = fn(&str) -> Result<String, Box<dyn Error>>;
+pub type DetailedSolver = fn(&str) -> Result<Answer, Box<dyn Error>>;
+pub type Player = fn(&str) -> Result<(), Box<dyn Error>>;
+
+enum Method {
+    Plain(Solver),
+    Detailed(DetailedSolver),
+}
+
+pub struct Puzzle {
+    day: u32,
+    part: Part,
+    method: Method,
+    player: Option<Player>,
+}
+
+impl Puzzle {
+    pub fn new(day: u32, part: Part, solver: Solver) -> Puzzle {
+        Puzzle {
+            day,
+            part,
+            method: Method::Plain(solver),
+            player: None,
+        }
+    }
+
+    pub fn detailed(day: u32, part: Part, solver: DetailedSolver) -> Puzzle {
+        Puzzle {
+            day,
+            part,
+            method: Method::Detailed(solver),
+            player: None,
+        }
+    }
+
+    pub fn interactive(mut self, player: Player) -> Puzzle {
+        self.player = Some(player);
+        self
+    }
+}
+
+impl Solution for Puzzle {
+    fn day(&self) -> u32 {
+        self.day
+    }
+
+    fn part(&self) -> Part {
+        self.part
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn Error>> {
+        match self.method {
+            Method::Plain(solver) => solver(input).map(Answer::new),
+            Method::Detailed(solver) => solver(input),
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.player.is_some()
+    }
+
+    fn play(&self, input: &str) -> Result<(), Box<dyn Error>> {
+        match self.player {
+            Some(player) => player(input),
+            None => Err(format!("Day {} {} is not interactive", self.day, self.part).into()),
+        }
+    }
+}
+
+pub fn option<'a>(args: &'a [String], name: &str) -> Result<Option<&'a str>, Box<dyn Error>> {
+    args.iter()
+        .position(|arg| arg == name)
+        .map(|index| {
+            args.get(index + 1)
+                .map(|value| value.as_str())
+                .ok_or_else(|| format!("{} needs a value", name).into())
+        })
+        .transpose()
+}
+
+pub fn load_input(solution: &dyn Solution, path: Option<&str>) -> Result<String, Box<dyn Error>> {
+    input::load(solution.day(), solution.part().letter(), path)
+}
+
+pub struct Outcome {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Result<Answer, String>,
+    pub elapsed: Duration,
+}
+
+pub fn run(solution: &dyn Solution, input: &str) -> Outcome {
+    let start = Instant::now();
+    let answer = solution.solve(input).map_err(|err| err.to_string());
+
+    Outcome {
+        day: solution.day(),
+        part: solution.part(),
+        answer,
+        elapsed: start.elapsed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run, Answer, Part, Puzzle, Solution};
+
+    #[test]
+    fn test_part() {
+        assert_eq!("a".parse::<Part>().unwrap(), Part::A);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::B);
+        assert!("c".parse::<Part>().is_err());
+        assert_eq!(Part::B.to_string(), "b");
+    }
+
+    #[test]
+    fn test_run() {
+        let double = Puzzle::new(1, Part::A, |input| {
+            Ok((input.trim().parse::<i32>()? * 2).to_string())
+        });
+        let halve = Puzzle::detailed(1, Part::B, |input| {
+            let value = input.trim().parse::<i32>()?;
+            Ok(Answer::new(value / 2).with_diagnostics(format!("remainder {}", value % 2)))
+        });
+        let broken = Puzzle::new(2, Part::A, |_| Err("Broken".into()));
+
+        assert!(!double.is_interactive());
+        assert!(double.play("").is_err());
+
+        assert_eq!(run(&double, "21\n").answer, Ok(Answer::new(42)));
+        assert_eq!(
+            run(&halve, "21\n").answer,
+            Ok(Answer::new(10).with_diagnostics("remainder 1"))
+        );
+        assert_eq!(run(&broken, "").answer, Err("Broken".to_string()));
+    }
+}
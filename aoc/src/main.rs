@@ -0,0 +1,119 @@
+use aoc::answers::{answers_path, report, Answers, Verdict};
+use aoc::days::solutions;
+use aoc::format::{render, Format};
+use aoc::{load_input, option, run, Outcome, Part};
+use std::env;
+use std::error::Error;
+
+const USAGE: &str = "Usage:
+  aoc list
+  aoc run [<day> [<part>]] [--input <path>|-] [--format text|json|csv] [--interactive]
+  aoc all [--format text|json|csv]
+  aoc verify [<day> [<part>]] [--input <path>|-] [--answers <path>]";
+
+const OPTIONS: [&str; 3] = ["--input", "--format", "--answers"];
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let input = option(&args, "--input")?;
+    let format = option(&args, "--format")?
+        .map(|format| format.parse::<Format>())
+        .transpose()?
+        .unwrap_or(Format::Text);
+
+    let interactive = args.iter().any(|arg| arg == "--interactive");
+
+    let positional = args
+        .iter()
+        .enumerate()
+        .filter(|(index, arg)| {
+            !arg.starts_with("--") && (*index == 0 || !OPTIONS.contains(&args[index - 1].as_str()))
+        })
+        .map(|(_, arg)| arg.as_str())
+        .collect::<Vec<_>>();
+
+    let verify = positional.first() == Some(&"verify");
+
+    let (day, part) = match positional.as_slice() {
+        ["list"] => {
+            for solution in solutions() {
+                let mode = if solution.is_interactive() {
+                    " (interactive)"
+                } else {
+                    ""
+                };
+
+                println!("{:>2} {}{}", solution.day(), solution.part(), mode);
+            }
+
+            return Ok(());
+        }
+        ["all"] | ["run"] | ["verify"] => (None, None),
+        ["run", day] | ["verify", day] => (Some(day.parse::<u32>()?), None),
+        ["run", day, part] | ["verify", day, part] => {
+            (Some(day.parse::<u32>()?), Some(part.parse::<Part>()?))
+        }
+        _ => return Err(USAGE.into()),
+    };
+
+    let selected = solutions()
+        .into_iter()
+        .filter(|solution| day.is_none_or(|day| solution.day() == day))
+        .filter(|solution| part.is_none_or(|part| solution.part() == part))
+        .collect::<Vec<_>>();
+
+    if selected.is_empty() {
+        return Err("No such puzzle".into());
+    }
+
+    if interactive {
+        let solution = match selected.as_slice() {
+            [solution] => solution,
+            _ => return Err("--interactive needs both a day and a part".into()),
+        };
+
+        return solution.play(&load_input(solution.as_ref(), input)?);
+    }
+
+    let outcomes = selected
+        .iter()
+        .map(|solution| match load_input(solution.as_ref(), input) {
+            Ok(input) => run(solution.as_ref(), &input),
+            Err(err) => Outcome {
+                day: solution.day(),
+                part: solution.part(),
+                answer: Err(err.to_string()),
+                elapsed: Default::default(),
+            },
+        })
+        .collect::<Vec<_>>();
+
+    if verify {
+        let answers = match option(&args, "--answers")? {
+            Some(path) => Answers::load(path)?,
+            None => Answers::load(answers_path())?,
+        };
+
+        print!("{}", report(&outcomes, &answers));
+
+        let failed = outcomes
+            .iter()
+            .filter(|outcome| answers.verify(outcome) == Verdict::Fail)
+            .count();
+
+        if failed > 0 {
+            return Err(format!("{} answers failed verification", failed).into());
+        }
+
+        return Ok(());
+    }
+
+    print!("{}", render(&outcomes, format));
+
+    if outcomes.iter().any(|outcome| outcome.answer.is_err()) {
+        return Err("Some puzzles failed".into());
+    }
+
+    Ok(())
+}
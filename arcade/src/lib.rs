@@ -0,0 +1,279 @@
+use int_comp::{IntcodeComputer, IntcodeOutput};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+pub mod strategy;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TileType {
+    Empty,
+    Wall,
+    Block,
+    Paddle,
+    Ball,
+}
+
+impl TileType {
+    pub fn to_char(self) -> char {
+        match self {
+            TileType::Empty => ' ',
+            TileType::Wall => '#',
+            TileType::Block => '=',
+            TileType::Paddle => '_',
+            TileType::Ball => 'o',
+        }
+    }
+}
+
+impl TryFrom<i64> for TileType {
+    type Error = &'static str;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => TileType::Empty,
+            1 => TileType::Wall,
+            2 => TileType::Block,
+            3 => TileType::Paddle,
+            4 => TileType::Ball,
+            _ => return Err("Unknown Tile"),
+        })
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Joystick {
+    Left,
+    Neutral,
+    Right,
+}
+
+impl Joystick {
+    pub fn as_i64(self) -> i64 {
+        match self {
+            Joystick::Left => -1,
+            Joystick::Neutral => 0,
+            Joystick::Right => 1,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Frame {
+    pub width: usize,
+    pub height: usize,
+    pub tiles: Vec<TileType>,
+    pub score: i64,
+}
+
+impl Frame {
+    pub fn get(&self, x: usize, y: usize) -> TileType {
+        self.tiles[x + y * self.width]
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Score: {}", self.score)?;
+
+        for row in self.tiles.chunks(self.width.max(1)) {
+            writeln!(f, "{}", row.iter().map(|t| t.to_char()).collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Arcade {
+    int_comp: IntcodeComputer,
+    screen: HashMap<(i64, i64), TileType>,
+    score: i64,
+    ball: Option<(i64, i64)>,
+    paddle: Option<(i64, i64)>,
+    frames: u64,
+    halted: bool,
+}
+
+impl Arcade {
+    pub fn new(program: &[i64]) -> Result<Arcade, Box<dyn Error>> {
+        let mut arcade = Arcade {
+            int_comp: IntcodeComputer::new(program),
+            screen: HashMap::new(),
+            score: 0,
+            ball: None,
+            paddle: None,
+            frames: 0,
+            halted: false,
+        };
+
+        arcade.run_frame(&[])?;
+
+        Ok(arcade)
+    }
+
+    pub fn free_play(program: &[i64]) -> Result<Arcade, Box<dyn Error>> {
+        let mut program = program.to_vec();
+        *program.first_mut().ok_or("Empty program")? = 2;
+
+        Arcade::new(&program)
+    }
+
+    pub fn step(&mut self, joystick: Joystick) -> Result<(), Box<dyn Error>> {
+        if self.halted {
+            return Err("Game over".into());
+        }
+
+        self.frames += 1;
+        self.run_frame(&[joystick.as_i64()])
+    }
+
+    fn run_frame(&mut self, input: &[i64]) -> Result<(), Box<dyn Error>> {
+        let mut input = input;
+
+        loop {
+            match self.int_comp.run(input, Some(3))? {
+                IntcodeOutput::Interrupt(output) => {
+                    self.draw(output[0], output[1], output[2])?;
+                    input = &[];
+                }
+                IntcodeOutput::Halt(_) => {
+                    self.halted = true;
+                    return Ok(());
+                }
+                IntcodeOutput::NeedMoreInput => return Ok(()),
+            }
+        }
+    }
+
+    fn draw(&mut self, x: i64, y: i64, value: i64) -> Result<(), Box<dyn Error>> {
+        if x == -1 && y == 0 {
+            self.score = value;
+            return Ok(());
+        }
+
+        let ty = TileType::try_from(value)?;
+
+        match ty {
+            TileType::Ball => self.ball = Some((x, y)),
+            TileType::Paddle => self.paddle = Some((x, y)),
+            _ => {}
+        }
+
+        self.screen.insert((x, y), ty);
+
+        Ok(())
+    }
+
+    pub fn score(&self) -> i64 {
+        self.score
+    }
+
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    pub fn ball(&self) -> Option<(i64, i64)> {
+        self.ball
+    }
+
+    pub fn paddle(&self) -> Option<(i64, i64)> {
+        self.paddle
+    }
+
+    pub fn blocks(&self) -> usize {
+        self.screen
+            .values()
+            .filter(|ty| **ty == TileType::Block)
+            .count()
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = ((i64, i64), TileType)> + '_ {
+        self.screen.iter().map(|(pos, ty)| (*pos, *ty))
+    }
+
+    pub fn frame(&self) -> Frame {
+        let width = self.screen.keys().map(|(x, _)| *x + 1).max().unwrap_or(0) as usize;
+        let height = self.screen.keys().map(|(_, y)| *y + 1).max().unwrap_or(0) as usize;
+
+        let mut tiles = vec![TileType::Empty; width * height];
+
+        for ((x, y), ty) in &self.screen {
+            if *x >= 0 && *y >= 0 {
+                tiles[*x as usize + *y as usize * width] = *ty;
+            }
+        }
+
+        Frame {
+            width,
+            height,
+            tiles,
+            score: self.score,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strategy::{self, Greedy, JoystickLog, Predictive, Replay};
+    use super::{Arcade, TileType};
+    use std::error::Error;
+
+    fn program() -> Vec<i64> {
+        include_str!("../../day_13_a/input")
+            .split(',')
+            .map(|v| v.trim().parse::<i64>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_boot() -> Result<(), Box<dyn Error>> {
+        let arcade = Arcade::new(&program())?;
+
+        assert!(arcade.is_halted());
+        assert_eq!(arcade.blocks(), 226);
+        assert!(Arcade::free_play(&[]).is_err());
+
+        let frame = arcade.frame();
+        assert_eq!(frame.get(0, 0), TileType::Wall);
+        assert_eq!(
+            frame
+                .tiles
+                .iter()
+                .filter(|t| **t == TileType::Block)
+                .count(),
+            226
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_strategies() -> Result<(), Box<dyn Error>> {
+        let mut greedy = Arcade::free_play(&program())?;
+        let greedy = strategy::play(&mut greedy, &mut Greedy, |_| Ok(()))?;
+
+        assert_eq!(greedy.score, 10800);
+        assert_eq!(greedy.blocks_remaining, 0);
+        assert_eq!(greedy.frames, greedy.log.0.len() as u64);
+
+        let mut predictive = Arcade::free_play(&program())?;
+        let predictive = strategy::play(&mut predictive, &mut Predictive::new(), |_| Ok(()))?;
+
+        assert_eq!(predictive.score, 10800);
+        assert_eq!(predictive.blocks_remaining, 0);
+
+        let log = greedy.log.to_string().parse::<JoystickLog>()?;
+        let mut replay = Arcade::free_play(&program())?;
+        let replay = strategy::play(&mut replay, &mut Replay::new(log), |_| Ok(()))?;
+
+        assert_eq!(replay.score, greedy.score);
+        assert_eq!(replay.log, greedy.log);
+
+        Ok(())
+    }
+}
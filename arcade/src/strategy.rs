@@ -0,0 +1,192 @@
+use super::{Arcade, Joystick};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+pub trait Strategy {
+    fn name(&self) -> &str;
+
+    fn next(&mut self, arcade: &Arcade) -> Result<Joystick, Box<dyn Error>>;
+}
+
+fn toward(from: i64, to: i64) -> Joystick {
+    if to < from {
+        Joystick::Left
+    } else if to > from {
+        Joystick::Right
+    } else {
+        Joystick::Neutral
+    }
+}
+
+pub struct Greedy;
+
+impl Strategy for Greedy {
+    fn name(&self) -> &str {
+        "greedy"
+    }
+
+    fn next(&mut self, arcade: &Arcade) -> Result<Joystick, Box<dyn Error>> {
+        let ball = arcade.ball().ok_or("No ball")?;
+        let paddle = arcade.paddle().ok_or("No paddle")?;
+
+        Ok(toward(paddle.0, ball.0))
+    }
+}
+
+#[derive(Default)]
+pub struct Predictive {
+    last_ball: Option<(i64, i64)>,
+}
+
+impl Predictive {
+    pub fn new() -> Predictive {
+        Predictive::default()
+    }
+}
+
+impl Strategy for Predictive {
+    fn name(&self) -> &str {
+        "predictive"
+    }
+
+    fn next(&mut self, arcade: &Arcade) -> Result<Joystick, Box<dyn Error>> {
+        let ball = arcade.ball().ok_or("No ball")?;
+        let paddle = arcade.paddle().ok_or("No paddle")?;
+        let last_ball = self.last_ball.replace(ball).unwrap_or(ball);
+
+        let dx = (ball.0 - last_ball.0).signum();
+        let dy = (ball.1 - last_ball.1).signum();
+
+        if dy <= 0 || ball.1 >= paddle.1 {
+            return Ok(toward(paddle.0, ball.0));
+        }
+
+        let width = arcade.frame().width as i64;
+        let (left, right) = (1, width - 2);
+
+        let mut x = ball.0;
+        let mut dx = dx;
+
+        for _ in 0..(paddle.1 - 1 - ball.1) {
+            if x + dx < left || x + dx > right {
+                dx = -dx;
+            }
+            x += dx;
+        }
+
+        Ok(toward(paddle.0, x))
+    }
+}
+
+pub struct Replay {
+    log: JoystickLog,
+    index: usize,
+}
+
+impl Replay {
+    pub fn new(log: JoystickLog) -> Replay {
+        Replay { log, index: 0 }
+    }
+}
+
+impl Strategy for Replay {
+    fn name(&self) -> &str {
+        "replay"
+    }
+
+    fn next(&mut self, _arcade: &Arcade) -> Result<Joystick, Box<dyn Error>> {
+        let joystick = *self.log.0.get(self.index).ok_or("Replay log exhausted")?;
+        self.index += 1;
+
+        Ok(joystick)
+    }
+}
+
+#[derive(Clone, Default, Eq, PartialEq, Debug)]
+pub struct JoystickLog(pub Vec<Joystick>);
+
+impl fmt::Display for JoystickLog {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for chunk in self.0.chunks(80) {
+            let line = chunk
+                .iter()
+                .map(|joystick| match joystick {
+                    Joystick::Left => 'L',
+                    Joystick::Neutral => 'N',
+                    Joystick::Right => 'R',
+                })
+                .collect::<String>();
+
+            writeln!(f, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for JoystickLog {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        input
+            .chars()
+            .filter(|ch| !ch.is_whitespace())
+            .map(|ch| match ch {
+                'L' => Ok(Joystick::Left),
+                'N' => Ok(Joystick::Neutral),
+                'R' => Ok(Joystick::Right),
+                _ => Err(format!("Invalid joystick input: {}", ch).into()),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(JoystickLog)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct GameReport {
+    pub strategy: String,
+    pub score: i64,
+    pub frames: u64,
+    pub blocks_remaining: usize,
+    pub log: JoystickLog,
+}
+
+impl fmt::Display for GameReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: score {}, frames {}, blocks remaining {}",
+            self.strategy, self.score, self.frames, self.blocks_remaining
+        )
+    }
+}
+
+pub fn play<S: Strategy + ?Sized, F>(
+    arcade: &mut Arcade,
+    strategy: &mut S,
+    mut on_frame: F,
+) -> Result<GameReport, Box<dyn Error>>
+where
+    F: FnMut(&Arcade) -> Result<(), Box<dyn Error>>,
+{
+    let mut log = JoystickLog::default();
+
+    while !arcade.is_halted() {
+        on_frame(arcade)?;
+
+        let joystick = strategy.next(arcade)?;
+        log.0.push(joystick);
+        arcade.step(joystick)?;
+    }
+
+    on_frame(arcade)?;
+
+    Ok(GameReport {
+        strategy: strategy.name().to_string(),
+        score: arcade.score(),
+        frames: arcade.frames(),
+        blocks_remaining: arcade.blocks(),
+        log,
+    })
+}
@@ -0,0 +1,378 @@
+use aoc::format::format_duration;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+pub mod suite;
+
+pub const MAX_SAMPLES: usize = 100;
+
+pub type Routine = Box<dyn FnMut() -> Result<(), Box<dyn Error>>>;
+
+pub struct Benchmark {
+    pub name: String,
+    pub routine: Routine,
+}
+
+impl Benchmark {
+    pub fn new<F>(name: &str, routine: F) -> Benchmark
+    where
+        F: FnMut() -> Result<(), Box<dyn Error>> + 'static,
+    {
+        Benchmark {
+            name: name.to_string(),
+            routine: Box::new(routine),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Measurement {
+    pub name: String,
+    pub samples: Vec<Duration>,
+}
+
+impl Measurement {
+    fn sorted(&self) -> Vec<Duration> {
+        let mut sorted = self.samples.clone();
+        sorted.sort();
+        sorted
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples.iter().max().copied().unwrap_or_default()
+    }
+
+    pub fn mean(&self) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::default();
+        }
+
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+
+    pub fn median(&self) -> Duration {
+        let sorted = self.sorted();
+
+        match sorted.len() {
+            0 => Duration::default(),
+            len if len % 2 == 0 => (sorted[len / 2 - 1] + sorted[len / 2]) / 2,
+            len => sorted[len / 2],
+        }
+    }
+}
+
+pub fn measure(benchmark: &mut Benchmark, budget: Duration) -> Result<Measurement, Box<dyn Error>> {
+    let warmup = Instant::now();
+    (benchmark.routine)()?;
+    let warmup = warmup.elapsed();
+
+    if warmup >= budget {
+        return Ok(Measurement {
+            name: benchmark.name.clone(),
+            samples: vec![warmup],
+        });
+    }
+
+    let mut samples = Vec::new();
+    let start = Instant::now();
+
+    while samples.is_empty() || (start.elapsed() < budget && samples.len() < MAX_SAMPLES) {
+        let sample = Instant::now();
+        (benchmark.routine)()?;
+        samples.push(sample.elapsed());
+    }
+
+    Ok(Measurement {
+        name: benchmark.name.clone(),
+        samples,
+    })
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct Baseline {
+    medians: BTreeMap<String, Duration>,
+}
+
+impl Baseline {
+    pub fn from_measurements(measurements: &[Measurement]) -> Baseline {
+        let mut baseline = Baseline::default();
+        baseline.update(measurements);
+        baseline
+    }
+
+    pub fn update(&mut self, measurements: &[Measurement]) {
+        for measurement in measurements {
+            self.medians
+                .insert(measurement.name.clone(), measurement.median());
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Baseline, Box<dyn Error>> {
+        let path = path.as_ref();
+
+        fs::read_to_string(path)
+            .map_err(|err| format!("Could not read baseline {}: {}", path.display(), err))?
+            .parse()
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn Error>> {
+        fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    pub fn median(&self, name: &str) -> Option<Duration> {
+        self.medians.get(name).copied()
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "name,median_ns")?;
+
+        for (name, median) in &self.medians {
+            writeln!(f, "{},{}", name, median.as_nanos())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Baseline {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut medians = BTreeMap::new();
+
+        for line in input.lines().skip(1).filter(|line| !line.trim().is_empty()) {
+            let (name, median) = line
+                .rsplit_once(',')
+                .ok_or_else(|| format!("Bad baseline line: {}", line))?;
+
+            medians.insert(
+                name.to_string(),
+                Duration::from_nanos(median.trim().parse()?),
+            );
+        }
+
+        Ok(Baseline { medians })
+    }
+}
+
+pub fn baseline_path() -> PathBuf {
+    input::workspace_dir()
+        .join("benchmark")
+        .join("baseline.csv")
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Change {
+    New,
+    Unchanged(f64),
+    Improved(f64),
+    Regressed(f64),
+}
+
+impl Change {
+    pub fn between(baseline: Option<Duration>, current: Duration, threshold: f64) -> Change {
+        let baseline = match baseline {
+            Some(baseline) if baseline > Duration::default() => baseline,
+            _ => return Change::New,
+        };
+
+        let percent = (current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0;
+
+        if percent > threshold {
+            Change::Regressed(percent)
+        } else if percent < -threshold {
+            Change::Improved(percent)
+        } else {
+            Change::Unchanged(percent)
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::New => f.pad("new"),
+            Change::Unchanged(percent) => f.pad(&format!("{:+.1}%", percent)),
+            Change::Improved(percent) => f.pad(&format!("{:+.1}% improved", percent)),
+            Change::Regressed(percent) => f.pad(&format!("{:+.1}% REGRESSED", percent)),
+        }
+    }
+}
+
+pub fn report(measurements: &[Measurement], baseline: &Baseline, threshold: f64) -> String {
+    let width = measurements
+        .iter()
+        .map(|measurement| measurement.name.len())
+        .chain(std::iter::once("Benchmark".len()))
+        .max()
+        .unwrap_or(0);
+
+    let mut report = format!(
+        "{:<width$}  {:>7}  {:>10}  {:>10}  {:>10}  {}\n",
+        "Benchmark",
+        "Samples",
+        "Min",
+        "Median",
+        "Max",
+        "Change",
+        width = width
+    );
+
+    for measurement in measurements {
+        let change = Change::between(
+            baseline.median(&measurement.name),
+            measurement.median(),
+            threshold,
+        );
+
+        report.push_str(&format!(
+            "{:<width$}  {:>7}  {:>10}  {:>10}  {:>10}  {}\n",
+            measurement.name,
+            measurement.samples.len(),
+            format_duration(measurement.min()),
+            format_duration(measurement.median()),
+            format_duration(measurement.max()),
+            change,
+            width = width
+        ));
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{measure, report, Baseline, Benchmark, Change, Measurement};
+    use std::cell::Cell;
+    use std::env;
+    use std::fs;
+    use std::process;
+    use std::rc::Rc;
+    use std::thread;
+    use std::time::Duration;
+
+    fn measurement(name: &str, millis: &[u64]) -> Measurement {
+        Measurement {
+            name: name.to_string(),
+            samples: millis.iter().map(|ms| Duration::from_millis(*ms)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_statistics() {
+        let odd = measurement("odd", &[5, 1, 3]);
+        assert_eq!(odd.min(), Duration::from_millis(1));
+        assert_eq!(odd.max(), Duration::from_millis(5));
+        assert_eq!(odd.mean(), Duration::from_millis(3));
+        assert_eq!(odd.median(), Duration::from_millis(3));
+
+        let even = measurement("even", &[4, 1, 2, 10]);
+        assert_eq!(even.median(), Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_measure() {
+        let calls = Rc::new(Cell::new(0));
+        let counter = calls.clone();
+        let mut benchmark = Benchmark::new("count", move || {
+            counter.set(counter.get() + 1);
+            Ok(())
+        });
+
+        let measurement = measure(&mut benchmark, Duration::from_millis(1)).unwrap();
+        assert!(!measurement.samples.is_empty());
+        assert!(measurement.samples.len() <= super::MAX_SAMPLES);
+        assert_eq!(calls.get(), measurement.samples.len() + 1);
+
+        let mut slow = Benchmark::new("slow", || {
+            thread::sleep(Duration::from_millis(2));
+            Ok(())
+        });
+        let measurement = measure(&mut slow, Duration::from_millis(1)).unwrap();
+        assert_eq!(measurement.samples.len(), 1);
+
+        let mut failing = Benchmark::new("fail", || Err("Broken".into()));
+        assert!(measure(&mut failing, Duration::from_millis(1)).is_err());
+    }
+
+    #[test]
+    fn test_baseline() {
+        let measurements = [
+            measurement("day 1 a", &[2]),
+            measurement("int_comp/run, day 9 b", &[10]),
+        ];
+
+        let baseline = Baseline::from_measurements(&measurements);
+        let parsed = baseline.to_string().parse::<Baseline>().unwrap();
+
+        assert_eq!(parsed.median("day 1 a"), Some(Duration::from_millis(2)));
+        assert_eq!(
+            parsed.median("int_comp/run, day 9 b"),
+            Some(Duration::from_millis(10))
+        );
+        assert_eq!(parsed.median("day 2 a"), None);
+        assert!("name,median_ns\nbroken".parse::<Baseline>().is_err());
+    }
+
+    #[test]
+    fn test_save_filtered_baseline() {
+        let path = env::temp_dir().join(format!("baseline_{}.csv", process::id()));
+
+        Baseline::from_measurements(&[measurement("day 1 a", &[2]), measurement("day 2 a", &[4])])
+            .save(&path)
+            .unwrap();
+
+        let mut baseline = Baseline::load(&path).unwrap();
+        baseline.update(&[measurement("day 2 a", &[8])]);
+        baseline.save(&path).unwrap();
+
+        let saved = Baseline::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(saved.median("day 1 a"), Some(Duration::from_millis(2)));
+        assert_eq!(saved.median("day 2 a"), Some(Duration::from_millis(8)));
+    }
+
+    #[test]
+    fn test_change() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(Change::between(None, ms(5), 10.0), Change::New);
+        assert_eq!(Change::between(Some(ms(0)), ms(5), 10.0), Change::New);
+        assert!(matches!(
+            Change::between(Some(ms(100)), ms(105), 10.0),
+            Change::Unchanged(p) if (p - 5.0).abs() < 1e-9
+        ));
+        assert!(matches!(
+            Change::between(Some(ms(100)), ms(150), 10.0),
+            Change::Regressed(p) if (p - 50.0).abs() < 1e-9
+        ));
+        assert!(matches!(
+            Change::between(Some(ms(100)), ms(50), 10.0),
+            Change::Improved(p) if (p + 50.0).abs() < 1e-9
+        ));
+
+        let baseline = Baseline::from_measurements(&[measurement("slow", &[10])]);
+        let report = report(
+            &[measurement("slow", &[20]), measurement("fresh", &[1])],
+            &baseline,
+            10.0,
+        );
+        let lines = report.lines().collect::<Vec<_>>();
+
+        assert!(lines[1].ends_with("+100.0% REGRESSED"));
+        assert!(lines[2].ends_with("new"));
+    }
+}
@@ -0,0 +1,83 @@
+use aoc::option;
+use benchmark::suite::benchmarks;
+use benchmark::{baseline_path, measure, report, Baseline, Change};
+use std::env;
+use std::error::Error;
+use std::time::Duration;
+
+const USAGE: &str = "Usage:
+  benchmark [<filter>] [--budget-ms <ms>] [--threshold <percent>]
+            [--baseline <path>] [--save-baseline]";
+
+const OPTIONS: [&str; 3] = ["--budget-ms", "--threshold", "--baseline"];
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    if args.iter().any(|arg| arg == "--help") {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+
+    let budget = Duration::from_millis(option(&args, "--budget-ms")?.unwrap_or("1000").parse()?);
+    let threshold = option(&args, "--threshold")?
+        .unwrap_or("10")
+        .parse::<f64>()?;
+    let path = option(&args, "--baseline")?
+        .map(Into::into)
+        .unwrap_or_else(baseline_path);
+    let save = args.iter().any(|arg| arg == "--save-baseline");
+
+    let filter = args
+        .iter()
+        .enumerate()
+        .find(|(index, arg)| {
+            !arg.starts_with("--") && (*index == 0 || !OPTIONS.contains(&args[index - 1].as_str()))
+        })
+        .map(|(_, arg)| arg.as_str());
+
+    let baseline = if path.exists() {
+        Baseline::load(&path)?
+    } else {
+        Baseline::default()
+    };
+
+    let mut measurements = Vec::new();
+
+    for mut benchmark in benchmarks()? {
+        if filter.is_none_or(|filter| benchmark.name.contains(filter)) {
+            eprintln!("Running {}", benchmark.name);
+            measurements.push(measure(&mut benchmark, budget)?);
+        }
+    }
+
+    print!("{}", report(&measurements, &baseline, threshold));
+
+    if save {
+        let mut saved = baseline.clone();
+        saved.update(&measurements);
+        saved.save(&path)?;
+        println!("Saved baseline to {}", path.display());
+        return Ok(());
+    }
+
+    let regressions = measurements
+        .iter()
+        .filter(|measurement| {
+            matches!(
+                Change::between(
+                    baseline.median(&measurement.name),
+                    measurement.median(),
+                    threshold
+                ),
+                Change::Regressed(_)
+            )
+        })
+        .count();
+
+    if regressions > 0 {
+        return Err(format!("{} benchmarks regressed", regressions).into());
+    }
+
+    Ok(())
+}
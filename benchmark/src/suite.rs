@@ -0,0 +1,79 @@
+use super::Benchmark;
+use aoc::days::solutions;
+use int_comp::{IntcodeComputer, IntcodeOutput};
+use std::convert::TryInto;
+use std::error::Error;
+use std::hint::black_box;
+
+fn parse_program(input: &str) -> Result<Vec<i64>, Box<dyn Error>> {
+    Ok(input
+        .split(',')
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?)
+}
+
+fn core() -> Result<Vec<Benchmark>, Box<dyn Error>> {
+    let boost = parse_program(&input::load(9, 'b', None)?)?;
+
+    let moons = day_12_b::parse_moons(&input::load(12, 'b', None)?)?;
+    let moons: [day_12_b::Moon; 4] = moons[..].try_into()?;
+
+    let reactions = day_14_b::parse_reactions(&input::load(14, 'b', None)?)?;
+
+    let signal = input::load(16, 'b', None)?;
+
+    let (map, pos, keys, _) = day_18_a::parse_map(&input::load(18, 'a', None)?)?;
+
+    Ok(vec![
+        Benchmark::new(
+            "int_comp/run (day 9 b)",
+            move || match IntcodeComputer::new(&boost).run(&[2], None)? {
+                IntcodeOutput::Halt(output) => {
+                    black_box(output);
+                    Ok(())
+                }
+                _ => Err("BOOST did not halt".into()),
+            },
+        ),
+        Benchmark::new("day_12_b/find_iterations", move || {
+            let mut moons = moons;
+            let mut gravitys = [day_12_b::Vec3::default(); 4];
+            black_box(day_12_b::find_iterations(&mut moons, &mut gravitys));
+            Ok(())
+        }),
+        Benchmark::new("day_14_b/find_fuel_for_ore", move || {
+            black_box(day_14_b::find_fuel_for_ore(&reactions, 1_000_000_000_000));
+            Ok(())
+        }),
+        Benchmark::new("day_16_b/fft_b", move || {
+            black_box(day_16_b::fft_b(&signal, 100)?);
+            Ok(())
+        }),
+        Benchmark::new("day_18_a/calculate_shortest_path", move || {
+            black_box(day_18_a::calculate_shortest_path(&map, pos, &keys));
+            Ok(())
+        }),
+    ])
+}
+
+fn days() -> Result<Vec<Benchmark>, Box<dyn Error>> {
+    let mut benchmarks = Vec::new();
+
+    for solution in solutions() {
+        let name = format!("day {} {}", solution.day(), solution.part());
+        let input = aoc::load_input(solution.as_ref(), None)?;
+
+        benchmarks.push(Benchmark::new(&name, move || {
+            black_box(solution.solve(&input)?);
+            Ok(())
+        }));
+    }
+
+    Ok(benchmarks)
+}
+
+pub fn benchmarks() -> Result<Vec<Benchmark>, Box<dyn Error>> {
+    let mut benchmarks = days()?;
+    benchmarks.extend(core()?);
+    Ok(benchmarks)
+}
@@ -0,0 +1,8 @@
+use painting_robot::{paint, Color};
+use std::error::Error;
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    let hull = paint(input, Color::Black)?;
+
+    Ok(hull.painted().to_string())
+}
@@ -0,0 +1,13 @@
+use arcade::Arcade;
+use std::error::Error;
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    let program = input
+        .split(',')
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let arcade = Arcade::new(&program)?;
+
+    Ok(arcade.blocks().to_string())
+}
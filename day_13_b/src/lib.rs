@@ -0,0 +1,118 @@
+use arcade::strategy::{self, GameReport, Greedy, Strategy};
+use arcade::{Arcade, Frame, Joystick, TileType};
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent},
+    style::{style, Color, PrintStyledContent},
+    terminal, ExecutableCommand,
+};
+use std::error::Error;
+use std::io::stdout;
+use std::thread;
+use std::time::Duration;
+
+pub struct Human;
+
+impl Strategy for Human {
+    fn name(&self) -> &str {
+        "human"
+    }
+
+    fn next(&mut self, _arcade: &Arcade) -> Result<Joystick, Box<dyn Error>> {
+        let mut joystick = Joystick::Neutral;
+
+        while event::poll(Duration::from_millis(100))? {
+            match event::read()? {
+                Event::Key(KeyEvent {
+                    code: KeyCode::Left,
+                    ..
+                }) => joystick = Joystick::Left,
+                Event::Key(KeyEvent {
+                    code: KeyCode::Right,
+                    ..
+                }) => joystick = Joystick::Right,
+                Event::Key(KeyEvent {
+                    code: KeyCode::Esc, ..
+                }) => return Err("Quit".into()),
+                _ => {}
+            }
+        }
+
+        Ok(joystick)
+    }
+}
+
+fn tile_color(ty: TileType) -> Color {
+    match ty {
+        TileType::Empty => Color::Black,
+        TileType::Wall => Color::Grey,
+        TileType::Block => Color::DarkGreen,
+        TileType::Paddle => Color::Yellow,
+        TileType::Ball => Color::Blue,
+    }
+}
+
+fn render(frame: &Frame, last_frame: Option<&Frame>) -> Result<(), Box<dyn Error>> {
+    let last_frame =
+        last_frame.filter(|last| last.width == frame.width && last.height == frame.height);
+
+    for y in 0..frame.height {
+        for x in 0..frame.width {
+            let ty = frame.get(x, y);
+
+            if last_frame.map(|last| last.get(x, y)) != Some(ty) {
+                stdout()
+                    .execute(cursor::MoveTo(x as u16, y as u16))?
+                    .execute(PrintStyledContent(style(ty.to_char()).with(tile_color(ty))))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn play(
+    input: &str,
+    strategy: &mut dyn Strategy,
+    headless: bool,
+) -> Result<GameReport, Box<dyn Error>> {
+    let program = input
+        .split(',')
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut arcade = Arcade::free_play(&program)?;
+    let mut last_frame = None;
+
+    if !headless {
+        stdout()
+            .execute(terminal::Clear(terminal::ClearType::All))?
+            .execute(cursor::Hide)?;
+        terminal::enable_raw_mode()?;
+    }
+
+    let report = strategy::play(&mut arcade, strategy, |arcade| {
+        if !headless {
+            let frame = arcade.frame();
+            render(&frame, last_frame.as_ref())?;
+            last_frame = Some(frame);
+
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        Ok(())
+    });
+
+    if !headless {
+        terminal::disable_raw_mode()?;
+        stdout()
+            .execute(terminal::Clear(terminal::ClearType::All))?
+            .execute(cursor::Show)?;
+    }
+
+    report
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    Ok(play(input, &mut Greedy, true)?.score.to_string())
+}
@@ -0,0 +1,190 @@
+use crossterm::{
+    cursor,
+    event::{read, Event, KeyCode, KeyEvent},
+    style::{style, Color, Print, PrintStyledContent},
+    terminal, ExecutableCommand,
+};
+use int_comp::{IntcodeComputer, IntcodeOutput};
+use repair_droid::{Exploration, Map, Tile};
+use std::error::Error;
+use std::io::stdout;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Input {
+    North,
+    South,
+    West,
+    East,
+}
+
+impl Input {
+    fn as_i32(self) -> i32 {
+        match self {
+            Input::North => 1,
+            Input::South => 2,
+            Input::West => 3,
+            Input::East => 4,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Output {
+    HitWall,
+    Moved,
+    MovedDone,
+}
+
+impl Output {
+    fn from_i32(input: i32) -> Self {
+        match input {
+            0 => Output::HitWall,
+            1 => Output::Moved,
+            2 => Output::MovedDone,
+            _ => panic!(),
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update(
+    pos: &mut (i32, i32),
+    map: &mut Map,
+    last_path_to_home: &mut Vec<(i32, i32)>,
+    input: Input,
+    output: Output,
+) -> Option<Vec<((i32, i32), char, Color)>> {
+    let mut res = Vec::new();
+
+    let old_pos = *pos;
+
+    let new_pos = match input {
+        Input::North => (pos.0, pos.1 + 1),
+        Input::South => (pos.0, pos.1 - 1),
+        Input::East => (pos.0 + 1, pos.1),
+        Input::West => (pos.0 - 1, pos.1),
+    };
+
+    if output == Output::MovedDone {
+        if let Some(path_to_home) = map.path((0, 0), new_pos) {
+            *last_path_to_home = path_to_home;
+        }
+        *pos = new_pos;
+        return None;
+    }
+
+    if output == Output::HitWall {
+        res.push((new_pos, '#', Color::Grey));
+        map.tiles.insert(new_pos, Tile::Wall);
+    } else {
+        res.push((old_pos, '.', Color::Grey));
+        res.push((new_pos, 'D', Color::Grey));
+        map.tiles.insert(new_pos, Tile::Ground);
+        *pos = new_pos;
+    }
+
+    if let Some(path_to_home) = map.path((0, 0), new_pos) {
+        for pos in last_path_to_home.iter() {
+            res.push((*pos, '.', Color::Grey));
+        }
+
+        for pos in path_to_home.iter() {
+            res.push((*pos, '.', Color::Blue));
+        }
+
+        *last_path_to_home = path_to_home;
+    }
+
+    Some(res)
+}
+
+const DRAW_OFFSET: (i32, i32) = (50, 50);
+
+pub fn explore(input: &str, render: bool) -> Result<Exploration, Box<dyn Error>> {
+    let program = input
+        .split(',')
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if render {
+        repair_droid::render::explore(&program, DRAW_OFFSET)
+    } else {
+        repair_droid::explore(&program, |_, _| Ok(()))
+    }
+}
+
+pub fn play(input: &str) -> Result<(), Box<dyn Error>> {
+    let program = input
+        .split(',')
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut int_comp = IntcodeComputer::new(&program);
+    let mut map = Map::new();
+    let mut pos = (0, 0);
+    let mut last_path_to_home = Vec::new();
+
+    map.tiles.insert(pos, Tile::Ground);
+
+    stdout()
+        .execute(terminal::Clear(terminal::ClearType::All))?
+        .execute(cursor::MoveTo(
+            (pos.0 + DRAW_OFFSET.0) as u16,
+            (pos.1 + DRAW_OFFSET.1) as u16,
+        ))?
+        .execute(Print('D'))?;
+
+    loop {
+        let input = match read()? {
+            Event::Key(KeyEvent {
+                code: KeyCode::Up, ..
+            }) => Input::South,
+            Event::Key(KeyEvent {
+                code: KeyCode::Down,
+                ..
+            }) => Input::North,
+            Event::Key(KeyEvent {
+                code: KeyCode::Left,
+                ..
+            }) => Input::West,
+            Event::Key(KeyEvent {
+                code: KeyCode::Right,
+                ..
+            }) => Input::East,
+            _ => continue,
+        };
+
+        let output = match int_comp.run(&[input.as_i32() as i64], Some(1))? {
+            IntcodeOutput::Interrupt(output) => Output::from_i32(output[0] as i32),
+            IntcodeOutput::Halt(_) => return Err("Halt")?,
+            IntcodeOutput::NeedMoreInput => return Err("Error")?,
+        };
+
+        if let Some(draw_instructions) =
+            update(&mut pos, &mut map, &mut last_path_to_home, input, output)
+        {
+            for (pos, ch, color) in draw_instructions {
+                stdout()
+                    .execute(cursor::MoveTo(
+                        (pos.0 + DRAW_OFFSET.0) as u16,
+                        (pos.1 + DRAW_OFFSET.1) as u16,
+                    ))?
+                    .execute(PrintStyledContent(style(ch).with(color)))?;
+            }
+        } else {
+            println!("Done: {}", last_path_to_home.len());
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    let exploration = explore(input, false)?;
+
+    Ok(exploration
+        .oxygen_distance()
+        .ok_or("No oxygen system found")?
+        .to_string())
+}
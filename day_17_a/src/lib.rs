@@ -0,0 +1,61 @@
+use crossterm::{
+    cursor,
+    style::{style, Color, PrintStyledContent},
+    terminal, ExecutableCommand,
+};
+use grid::SparseGrid;
+use int_comp::IntcodeComputer;
+use std::error::Error;
+use std::io::stdout;
+
+pub fn run(input: &str, render: bool) -> Result<i32, Box<dyn Error>> {
+    let program = input
+        .split(",")
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut int_comp = IntcodeComputer::new(&program);
+
+    let output = int_comp.run(&[], None)?;
+
+    let camera = output
+        .data()
+        .iter()
+        .map(|c| *c as u8 as char)
+        .collect::<String>();
+
+    let map = SparseGrid::parse_with(&camera, |ch| match ch {
+        '#' => Some(Some(())),
+        '.' | '^' | 'v' | 'V' | '<' | '>' => Some(None),
+        _ => None,
+    })?;
+
+    let intersections = map
+        .positions()
+        .filter(|pos| map.neighbors4(*pos).count() == 4)
+        .collect::<Vec<_>>();
+
+    if render {
+        stdout().execute(terminal::Clear(terminal::ClearType::All))?;
+
+        for pos in map.positions() {
+            stdout()
+                .execute(cursor::MoveTo(pos.0 as u16, pos.1 as u16))?
+                .execute(PrintStyledContent(style('#').with(Color::Blue)))?;
+        }
+
+        for pos in &intersections {
+            stdout()
+                .execute(cursor::MoveTo(pos.0 as u16, pos.1 as u16))?
+                .execute(PrintStyledContent(style('O').with(Color::Red)))?;
+        }
+
+        stdout().execute(cursor::MoveTo(0, 0))?;
+    }
+
+    Ok(intersections.iter().map(|(x, y)| x * y).sum::<i32>())
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    Ok(run(input, false)?.to_string())
+}
@@ -0,0 +1,122 @@
+use super::Instruction;
+use std::fmt;
+
+pub const MAX_ROUTINE_LEN: usize = 20;
+pub const FUNCTION_NAMES: [char; 3] = ['A', 'B', 'C'];
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Routines {
+    pub main: Vec<usize>,
+    pub functions: Vec<Vec<Instruction>>,
+}
+
+impl Routines {
+    pub fn expand(&self) -> Vec<Instruction> {
+        self.main
+            .iter()
+            .flat_map(|function| self.functions[*function].iter().copied())
+            .collect()
+    }
+
+    pub fn main_routine(&self) -> String {
+        self.main
+            .iter()
+            .map(|function| FUNCTION_NAMES[*function].to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    pub fn to_input(&self, video_feed: bool) -> String {
+        let mut input = format!("{}\n", self.main_routine());
+
+        for name in 0..FUNCTION_NAMES.len() {
+            let function = self.functions.get(name).map_or(&[][..], |f| &f[..]);
+            input.push_str(&format!("{}\n", routine(function)));
+        }
+
+        input.push_str(if video_feed { "y\n" } else { "n\n" });
+        input
+    }
+}
+
+impl fmt::Display for Routines {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Main: {}", self.main_routine())?;
+
+        for (name, function) in FUNCTION_NAMES.iter().zip(&self.functions) {
+            writeln!(f, "{}: {}", name, routine(function))?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn routine(instructions: &[Instruction]) -> String {
+    instructions
+        .iter()
+        .map(|instruction| instruction.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn search(
+    instructions: &[Instruction],
+    main: &mut Vec<usize>,
+    functions: &mut Vec<Vec<Instruction>>,
+) -> bool {
+    if instructions.is_empty() {
+        return true;
+    }
+
+    if (main.len() + 1) * 2 - 1 > MAX_ROUTINE_LEN {
+        return false;
+    }
+
+    for function in 0..functions.len() {
+        let len = functions[function].len();
+
+        if instructions.starts_with(&functions[function]) {
+            main.push(function);
+
+            if search(&instructions[len..], main, functions) {
+                return true;
+            }
+
+            main.pop();
+        }
+    }
+
+    if functions.len() == FUNCTION_NAMES.len() {
+        return false;
+    }
+
+    let longest = (1..=instructions.len())
+        .take_while(|len| routine(&instructions[..*len]).len() <= MAX_ROUTINE_LEN)
+        .last()
+        .unwrap_or(0);
+
+    for len in (1..=longest).rev() {
+        functions.push(instructions[..len].to_vec());
+        main.push(functions.len() - 1);
+
+        if search(&instructions[len..], main, functions) {
+            return true;
+        }
+
+        main.pop();
+        functions.pop();
+    }
+
+    false
+}
+
+pub fn compress(instructions: &[Instruction]) -> Option<Routines> {
+    let mut main = Vec::new();
+    let mut functions = Vec::new();
+
+    if search(instructions, &mut main, &mut functions) {
+        Some(Routines { main, functions })
+    } else {
+        None
+    }
+}
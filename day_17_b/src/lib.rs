@@ -0,0 +1,282 @@
+use compress::Routines;
+use grid::{Pos, SparseGrid};
+use int_comp::{IntcodeComputer, IntcodeOutput};
+use std::error::Error;
+use std::fmt;
+
+pub mod compress;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Dir {
+    Right,
+    Left,
+    Up,
+    Down,
+}
+
+impl Dir {
+    fn from_char(ch: char) -> Option<Dir> {
+        match ch {
+            '>' => Some(Dir::Right),
+            '<' => Some(Dir::Left),
+            '^' => Some(Dir::Up),
+            'v' | 'V' => Some(Dir::Down),
+            _ => None,
+        }
+    }
+
+    fn get_next(self, pos: (i32, i32)) -> (i32, i32) {
+        match self {
+            Dir::Right => (pos.0 + 1, pos.1),
+            Dir::Left => (pos.0 - 1, pos.1),
+            Dir::Up => (pos.0, pos.1 - 1),
+            Dir::Down => (pos.0, pos.1 + 1),
+        }
+    }
+
+    fn turn_left(self) -> Dir {
+        match self {
+            Dir::Right => Dir::Up,
+            Dir::Up => Dir::Left,
+            Dir::Left => Dir::Down,
+            Dir::Down => Dir::Right,
+        }
+    }
+
+    fn turn_right(self) -> Dir {
+        match self {
+            Dir::Right => Dir::Down,
+            Dir::Down => Dir::Left,
+            Dir::Left => Dir::Up,
+            Dir::Up => Dir::Right,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Instruction {
+    TurnAround,
+    Forward(i32),
+    Left(i32),
+    Right(i32),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::TurnAround => write!(f, "R,R"),
+            Instruction::Forward(len) => write!(f, "{}", len),
+            Instruction::Left(len) => write!(f, "L,{}", len),
+            Instruction::Right(len) => write!(f, "R,{}", len),
+        }
+    }
+}
+
+impl Instruction {
+    fn calculate(
+        map: &SparseGrid<char>,
+        start_pos: (i32, i32),
+        start_dir: Dir,
+    ) -> Vec<Instruction> {
+        let is_scaffold = |pos: (i32, i32)| map.get(pos) == Some(&'#');
+
+        let mut res = Vec::new();
+        let mut pos = start_pos;
+        let mut dir = start_dir;
+        let mut turn: fn(i32) -> Instruction = Instruction::Forward;
+
+        let behind = dir.turn_left().turn_left();
+
+        if [dir, dir.turn_left(), dir.turn_right()]
+            .iter()
+            .all(|dir| !is_scaffold(dir.get_next(pos)))
+            && is_scaffold(behind.get_next(pos))
+        {
+            res.push(Instruction::TurnAround);
+            dir = behind;
+        }
+
+        loop {
+            let mut len = 0;
+
+            while is_scaffold(dir.get_next(pos)) {
+                pos = dir.get_next(pos);
+                len += 1;
+            }
+
+            if len > 0 {
+                res.push(turn(len));
+            }
+
+            turn = if is_scaffold(dir.turn_left().get_next(pos)) {
+                dir = dir.turn_left();
+                Instruction::Left
+            } else if is_scaffold(dir.turn_right().get_next(pos)) {
+                dir = dir.turn_right();
+                Instruction::Right
+            } else {
+                break;
+            };
+        }
+
+        res
+    }
+}
+
+type Scaffold = (SparseGrid<char>, Pos, Dir);
+
+fn parse_map(camera: &str) -> Result<Scaffold, Box<dyn Error>> {
+    let map = camera.parse::<SparseGrid<char>>()?;
+
+    let (start_pos, start_dir) = map
+        .iter()
+        .find_map(|(pos, ch)| Dir::from_char(*ch).map(|dir| (pos, dir)))
+        .ok_or("No robot on the camera image")?;
+
+    Ok((map, start_pos, start_dir))
+}
+
+fn camera_image(program: &[i64]) -> Result<String, Box<dyn Error>> {
+    let mut int_comp = IntcodeComputer::new(program);
+    let output = int_comp.run(&[], None)?;
+
+    Ok(output.data().iter().map(|c| *c as u8 as char).collect())
+}
+
+pub fn collect_dust(
+    input: &str,
+    video_feed: bool,
+) -> Result<(Routines, IntcodeOutput), Box<dyn Error>> {
+    let mut program = input
+        .split(',')
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (map, start_pos, start_dir) = parse_map(&camera_image(&program)?)?;
+
+    let instr = Instruction::calculate(&map, start_pos, start_dir);
+
+    if instr.is_empty() {
+        return Err("No scaffold next to the robot".into());
+    }
+
+    let routines = compress::compress(&instr).ok_or("No routines fit the memory limits")?;
+
+    if routines.expand() != instr {
+        return Err("Routines do not reproduce the scaffold path".into());
+    }
+
+    program[0] = 2;
+
+    let input = routines.to_input(video_feed);
+
+    let mut int_comp = IntcodeComputer::new(&program);
+
+    let output = int_comp.run(&input.chars().map(|c| c as i64).collect::<Vec<_>>(), None)?;
+
+    Ok((routines, output))
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    let (_, output) = collect_dust(input, false)?;
+
+    Ok(output.data().last().ok_or("No output")?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compress::{self, routine, MAX_ROUTINE_LEN};
+    use super::{camera_image, parse_map, Instruction};
+
+    const EXAMPLE: &str = "#######...#####
+#.....#...#...#
+#.....#...#...#
+......#...#...#
+......#...###.#
+......#.....#.#
+^########...#.#
+......#.#...#.#
+......#########
+........#...#..
+....#########..
+....#...#......
+....#...#......
+....#...#......
+....#####......";
+
+    fn check(instr: &[Instruction]) {
+        let routines = compress::compress(instr).unwrap();
+
+        assert_eq!(routines.expand(), instr);
+        assert!(routines.main_routine().len() <= MAX_ROUTINE_LEN);
+
+        for function in &routines.functions {
+            assert!(routine(function).len() <= MAX_ROUTINE_LEN);
+        }
+    }
+
+    #[test]
+    fn test_calculate() {
+        let (map, start_pos, start_dir) = parse_map(EXAMPLE).unwrap();
+        let instr = Instruction::calculate(&map, start_pos, start_dir);
+
+        assert_eq!(
+            routine(&instr),
+            "R,8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2"
+        );
+
+        check(&instr);
+    }
+
+    #[test]
+    fn test_calculate_facing_scaffold() {
+        let (map, start_pos, start_dir) = parse_map(&EXAMPLE.replace('^', ">")).unwrap();
+        let instr = Instruction::calculate(&map, start_pos, start_dir);
+
+        assert_eq!(
+            routine(&instr),
+            "8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2"
+        );
+
+        check(&instr);
+    }
+
+    #[test]
+    fn test_calculate_facing_away() {
+        let (map, start_pos, start_dir) = parse_map(&EXAMPLE.replace('^', "<")).unwrap();
+        let instr = Instruction::calculate(&map, start_pos, start_dir);
+
+        assert_eq!(
+            routine(&instr),
+            "R,R,8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2"
+        );
+
+        check(&instr);
+
+        let (map, start_pos, start_dir) = parse_map("...\n.^.\n...").unwrap();
+        assert!(Instruction::calculate(&map, start_pos, start_dir).is_empty());
+    }
+
+    #[test]
+    fn test_compress() {
+        let program = include_str!("../input")
+            .split(',')
+            .map(|v| v.trim().parse::<i64>().unwrap())
+            .collect::<Vec<_>>();
+
+        let (map, start_pos, start_dir) = parse_map(&camera_image(&program).unwrap()).unwrap();
+        let instr = Instruction::calculate(&map, start_pos, start_dir);
+
+        check(&instr);
+
+        let routines = compress::compress(&instr).unwrap();
+        assert_eq!(routines.to_input(false).lines().count(), 5);
+    }
+
+    #[test]
+    fn test_no_solution() {
+        let instr = (1..=40).map(Instruction::Left).collect::<Vec<_>>();
+
+        assert_eq!(compress::compress(&instr), None);
+    }
+}
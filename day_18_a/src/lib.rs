@@ -0,0 +1,127 @@
+use grid::Pos;
+use std::error::Error;
+use vault::{KeySet, Map, Positions};
+
+pub fn parse_map(input: &str) -> Result<(Map, Pos, Positions, Positions), Box<dyn Error>> {
+    let (map, entrances, keys, doors) = vault::parse_map(input)?;
+
+    let start_pos = *entrances.first().ok_or("No entrance")?;
+
+    Ok((map, start_pos, keys, doors))
+}
+
+pub fn calculate_shortest_path(map: &Map, pos: Pos, keys: &[(char, Pos)]) -> Option<i32> {
+    let paths = vault::calculate_key_graph(map, &[pos], keys);
+    let all_keys = vault::all_keys(keys);
+
+    search::dijkstra(
+        (pos, 0),
+        |(pos, aquired_keys): &(Pos, KeySet)| {
+            paths[pos]
+                .iter()
+                .filter(|path| path.is_open(*aquired_keys))
+                .map(|path| {
+                    (
+                        (path.end_pos, aquired_keys | vault::key_bit(path.key)),
+                        path.len,
+                    )
+                })
+                .collect::<Vec<_>>()
+        },
+        |(_, aquired_keys)| *aquired_keys == all_keys,
+    )
+    .map(|(_, len)| len)
+}
+
+pub fn run(input: &str, render: bool) -> Result<i32, Box<dyn Error>> {
+    let (map, pos, keys, _) = parse_map(input)?;
+
+    let shortest_path = calculate_shortest_path(&map, pos, &keys).ok_or("No Path")?;
+
+    if render {
+        vault::render(&map)?;
+    }
+
+    Ok(shortest_path)
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    Ok(run(input, false)?.to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod tests {
+    use super::{calculate_shortest_path, parse_map};
+
+    #[test]
+    fn test_calculate_shortest_path() {
+        {
+            let input = "#########
+#b.A.@.a#
+#########";
+
+            let (map, pos, keys, _) = parse_map(&input).unwrap();
+            let shortest_path = calculate_shortest_path(&map, pos, &keys);
+
+            assert_eq!(shortest_path, Some(8))
+        }
+
+        {
+            let input = "########################
+#f.D.E.e.C.b.A.@.a.B.c.#
+######################.#
+#d.....................#
+########################";
+
+            let (map, pos, keys, _) = parse_map(&input).unwrap();
+            let shortest_path = calculate_shortest_path(&map, pos, &keys);
+
+            assert_eq!(shortest_path, Some(86))
+        }
+
+        {
+            let input = "########################
+#...............b.C.D.f#
+#.######################
+#.....@.a.B.c.d.A.e.F.g#
+########################";
+
+            let (map, pos, keys, _) = parse_map(&input).unwrap();
+            let shortest_path = calculate_shortest_path(&map, pos, &keys);
+
+            assert_eq!(shortest_path, Some(132))
+        }
+
+        {
+            let input = "#################
+#i.G..c...e..H.p#
+########.########
+#j.A..b...f..D.o#
+########@########
+#k.E..a...g..B.n#
+########.########
+#l.F..d...h..C.m#
+#################";
+
+            let (map, pos, keys, _) = parse_map(&input).unwrap();
+            let shortest_path = calculate_shortest_path(&map, pos, &keys);
+
+            assert_eq!(shortest_path, Some(136))
+        }
+
+        {
+            let input = "########################
+#@..............ac.GI.b#
+###d#e#f################
+###A#B#C################
+###g#h#i################
+########################";
+
+            let (map, pos, keys, _) = parse_map(&input).unwrap();
+            let shortest_path = calculate_shortest_path(&map, pos, &keys);
+
+            assert_eq!(shortest_path, Some(81))
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use grid::Pos;
+use std::convert::TryInto;
+use std::error::Error;
+use vault::{KeySet, Map, Positions};
+
+type Vault = (Map, [Pos; 4], Positions, Positions);
+
+fn parse_map(input: &str) -> Result<Vault, Box<dyn Error>> {
+    let (map, entrances, keys, doors) = vault::parse_map(input)?;
+
+    let start_pos: [Pos; 4] = entrances[..]
+        .try_into()
+        .map_err(|_| "Expected four entrances")?;
+
+    Ok((map, start_pos, keys, doors))
+}
+
+fn calculate_shortest_path(map: &Map, pos: [Pos; 4], keys: &[(char, Pos)]) -> Option<i32> {
+    let paths = vault::calculate_key_graph(map, &pos, keys);
+    let all_keys = vault::all_keys(keys);
+
+    search::dijkstra(
+        (pos, 0),
+        |(pos, aquired_keys): &([Pos; 4], KeySet)| {
+            let mut res = Vec::new();
+
+            for (i, start_pos) in pos.iter().enumerate() {
+                for path in paths[start_pos]
+                    .iter()
+                    .filter(|path| path.is_open(*aquired_keys))
+                {
+                    let mut new_pos = *pos;
+                    new_pos[i] = path.end_pos;
+
+                    res.push(((new_pos, aquired_keys | vault::key_bit(path.key)), path.len));
+                }
+            }
+
+            res
+        },
+        |(_, aquired_keys)| *aquired_keys == all_keys,
+    )
+    .map(|(_, len)| len)
+}
+
+pub fn run(input: &str, render: bool) -> Result<i32, Box<dyn Error>> {
+    let (map, pos, keys, _) = parse_map(input)?;
+
+    let shortest_path = calculate_shortest_path(&map, pos, &keys).ok_or("No Path")?;
+
+    if render {
+        vault::render(&map)?;
+    }
+
+    Ok(shortest_path)
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    Ok(run(input, false)?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calculate_shortest_path, parse_map};
+
+    #[test]
+    fn test_calculate_shortest_path_b() {
+        {
+            let input = "#######
+#a.#Cd#
+##@#@##
+#######
+##@#@##
+#cB#Ab#
+#######";
+
+            let (map, pos, keys, _) = parse_map(input).unwrap();
+            let shortest_path = calculate_shortest_path(&map, pos, &keys);
+
+            assert_eq!(shortest_path, Some(8))
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use std::error::Error;
+use tractor_beam::TractorBeam;
+
+pub const SIZE: i64 = 50;
+
+pub fn parse_program(input: &str) -> Result<Vec<i64>, Box<dyn Error>> {
+    Ok(input
+        .split(',')
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?)
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    let mut beam = TractorBeam::new(&parse_program(input)?);
+
+    Ok(beam.count(SIZE, SIZE)?.to_string())
+}
@@ -0,0 +1,30 @@
+use std::error::Error;
+
+fn calculate_fuel(mass: i32) -> i32 {
+    mass / 3 - 2
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    let mut total_fuel = 0;
+
+    for line in input.lines() {
+        let mass = line.parse::<i32>()?;
+
+        total_fuel += calculate_fuel(mass);
+    }
+
+    Ok(total_fuel.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::calculate_fuel;
+
+    #[test]
+    fn test_calculate_fuel() {
+        assert_eq!(calculate_fuel(12), 2);
+        assert_eq!(calculate_fuel(14), 2);
+        assert_eq!(calculate_fuel(1969), 654);
+        assert_eq!(calculate_fuel(100756), 33583);
+    }
+}
@@ -0,0 +1,234 @@
+use crossterm::{
+    cursor,
+    style::{style, Color, Print, PrintStyledContent},
+    terminal, ExecutableCommand,
+};
+use donut::{parse_map, Map, Tile};
+use grid::Pos;
+use std::collections::HashSet;
+use std::error::Error;
+use std::io::stdout;
+
+fn shortest_path(start: Pos, goal: Pos, map: &Map) -> Option<Vec<Pos>> {
+    search::dijkstra(
+        start,
+        |pos| donut::neighbors(*pos, map).map(|(next, _)| (next, 1)),
+        |pos| *pos == goal,
+    )
+    .map(|(path, _)| path)
+}
+
+pub fn run(input: &str, render: bool) -> Result<usize, Box<dyn Error>> {
+    let map = parse_map(input)?;
+
+    let start_pos = map
+        .tiles()
+        .find_map(|tile| tile.get_destination_if_name_matches(b"AA"))
+        .ok_or("No Start")?;
+    let end_pos = map
+        .tiles()
+        .find_map(|tile| tile.get_destination_if_name_matches(b"ZZ"))
+        .ok_or("No End")?;
+
+    let path = shortest_path(start_pos, end_pos, &map).ok_or("No Path")?;
+
+    if render {
+        stdout().execute(terminal::Clear(terminal::ClearType::All))?;
+
+        for (pos, tile) in map.iter() {
+            stdout()
+                .execute(cursor::MoveTo(pos.0 as u16, pos.1 as u16))?
+                .execute(PrintStyledContent(tile.to_styled_char()))?;
+        }
+
+        for pos in &path {
+            stdout()
+                .execute(cursor::MoveTo(pos.0 as u16, pos.1 as u16))?
+                .execute(PrintStyledContent(style('.').with(Color::Blue)))?;
+        }
+
+        {
+            let mut drawn = HashSet::new();
+            for (portal_pos, tile) in map.iter() {
+                if let Tile::Portal(pos, name, _) = tile {
+                    if drawn.contains(name) {
+                        stdout()
+                            .execute(cursor::MoveTo(portal_pos.0 as u16, portal_pos.1 as u16))?
+                            .execute(PrintStyledContent(
+                                style(name[0] as char).with(Color::Green),
+                            ))?;
+
+                        stdout()
+                            .execute(cursor::MoveTo(pos.0 as u16, pos.1 as u16))?
+                            .execute(PrintStyledContent(
+                                style(name[0].to_ascii_lowercase() as char).with(Color::Green),
+                            ))?;
+                    } else {
+                        stdout()
+                            .execute(cursor::MoveTo(portal_pos.0 as u16, portal_pos.1 as u16))?
+                            .execute(PrintStyledContent(style(name[0] as char).with(Color::Red)))?;
+
+                        stdout()
+                            .execute(cursor::MoveTo(pos.0 as u16, pos.1 as u16))?
+                            .execute(PrintStyledContent(
+                                style(name[0].to_ascii_lowercase() as char).with(Color::Red),
+                            ))?;
+
+                        drawn.insert(*name);
+                    }
+                }
+            }
+        }
+
+        {
+            let max_pos = map.bounds().ok_or("Error")?.max;
+            stdout()
+                .execute(cursor::MoveTo(0, max_pos.1 as u16))?
+                .execute(Print('\n'))?;
+        }
+    }
+
+    Ok(path.len() - 1)
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    Ok(run(input, false)?.to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod tests {
+    use super::{parse_map, shortest_path};
+
+    #[test]
+    fn test_20_a() {
+        {
+            let input = "         A           
+         A           
+  #######.#########  
+  #######.........#  
+  #######.#######.#  
+  #######.#######.#  
+  #######.#######.#  
+  #####  B    ###.#  
+BC...##  C    ###.#  
+  ##.##       ###.#  
+  ##...DE  F  ###.#  
+  #####    G  ###.#  
+  #########.#####.#  
+DE..#######...###.#  
+  #.#########.###.#  
+FG..#########.....#  
+  ###########.#####  
+             Z       
+             Z       ";
+
+            let map = parse_map(&input).unwrap();
+
+            let start_pos = map
+                .tiles()
+                .find_map(|tile| tile.get_destination_if_name_matches(b"AA"))
+                .unwrap();
+            let end_pos = map
+                .tiles()
+                .find_map(|tile| tile.get_destination_if_name_matches(b"ZZ"))
+                .unwrap();
+
+            let path = shortest_path(start_pos, end_pos, &map).unwrap();
+
+            assert_eq!(path.len() - 1, 23)
+        }
+
+        {
+            let input = "                   A               
+                   A               
+  #################.#############  
+  #.#...#...................#.#.#  
+  #.#.#.###.###.###.#########.#.#  
+  #.#.#.......#...#.....#.#.#...#  
+  #.#########.###.#####.#.#.###.#  
+  #.............#.#.....#.......#  
+  ###.###########.###.#####.#.#.#  
+  #.....#        A   C    #.#.#.#  
+  #######        S   P    #####.#  
+  #.#...#                 #......VT
+  #.#.#.#                 #.#####  
+  #...#.#               YN....#.#  
+  #.###.#                 #####.#  
+DI....#.#                 #.....#  
+  #####.#                 #.###.#  
+ZZ......#               QG....#..AS
+  ###.###                 #######  
+JO..#.#.#                 #.....#  
+  #.#.#.#                 ###.#.#  
+  #...#..DI             BU....#..LF
+  #####.#                 #.#####  
+YN......#               VT..#....QG
+  #.###.#                 #.###.#  
+  #.#...#                 #.....#  
+  ###.###    J L     J    #.#.###  
+  #.....#    O F     P    #.#...#  
+  #.###.#####.#.#####.#####.###.#  
+  #...#.#.#...#.....#.....#.#...#  
+  #.#####.###.###.#.#.#########.#  
+  #...#.#.....#...#.#.#.#.....#.#  
+  #.###.#####.###.###.#.#.#######  
+  #.#.........#...#.............#  
+  #########.###.###.#############  
+           B   J   C               
+           U   P   P               ";
+
+            let map = parse_map(&input).unwrap();
+
+            let start_pos = map
+                .tiles()
+                .find_map(|tile| tile.get_destination_if_name_matches(b"AA"))
+                .unwrap();
+            let end_pos = map
+                .tiles()
+                .find_map(|tile| tile.get_destination_if_name_matches(b"ZZ"))
+                .unwrap();
+
+            let path = shortest_path(start_pos, end_pos, &map).unwrap();
+
+            assert_eq!(path.len() - 1, 58)
+        }
+    }
+
+    #[test]
+    fn test_reversed_portal_names() {
+        let input = "   A     K     Q   
+   A     Q     K   
+###.#####.#####.###
+###.#####.#####.###
+###.#####.#####.###
+###.#####.#####.###
+###K#####Z#####Q###
+   Q     Z     K   ";
+
+        let map = parse_map(input).unwrap();
+
+        let count = |name: &[u8]| {
+            map.tiles()
+                .filter_map(|tile| tile.get_portal())
+                .filter(|(_, portal_name, _)| portal_name == name)
+                .count()
+        };
+
+        assert_eq!(count(b"KQ"), 2);
+        assert_eq!(count(b"QK"), 2);
+
+        let start_pos = map
+            .tiles()
+            .find_map(|tile| tile.get_destination_if_name_matches(b"AA"))
+            .unwrap();
+        let end_pos = map
+            .tiles()
+            .find_map(|tile| tile.get_destination_if_name_matches(b"ZZ"))
+            .unwrap();
+
+        let path = shortest_path(start_pos, end_pos, &map).unwrap();
+
+        assert_eq!(path.len() - 1, 7)
+    }
+}
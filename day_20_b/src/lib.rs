@@ -0,0 +1,218 @@
+use crossterm::{
+    cursor,
+    style::{style, Color, Print, PrintStyledContent},
+    terminal, ExecutableCommand,
+};
+use donut::{parse_map, Map, Tile};
+use grid::Pos;
+use std::collections::HashSet;
+use std::error::Error;
+use std::io::stdout;
+
+fn shortest_path(start: Pos, goal: Pos, map: &Map) -> Option<Vec<(i32, i32, i32)>> {
+    search::dijkstra(
+        (start.0, start.1, 0),
+        |&(x, y, z)| {
+            donut::neighbors((x, y), map)
+                .filter(move |(_, z_diff)| z + z_diff >= 0)
+                .map(move |(next, z_diff)| ((next.0, next.1, z + z_diff), 1))
+        },
+        |pos| *pos == (goal.0, goal.1, 0),
+    )
+    .map(|(path, _)| path)
+}
+
+pub fn run(input: &str, render: bool) -> Result<usize, Box<dyn Error>> {
+    let map = parse_map(input)?;
+
+    let start_pos = map
+        .tiles()
+        .find_map(|tile| tile.get_destination_if_name_matches(b"AA"))
+        .ok_or("No Start")?;
+    let end_pos = map
+        .tiles()
+        .find_map(|tile| tile.get_destination_if_name_matches(b"ZZ"))
+        .ok_or("No End")?;
+
+    let path = shortest_path(start_pos, end_pos, &map).ok_or("No Path")?;
+
+    if render {
+        stdout().execute(terminal::Clear(terminal::ClearType::All))?;
+
+        for (pos, tile) in map.iter() {
+            stdout()
+                .execute(cursor::MoveTo(2 * pos.0 as u16, pos.1 as u16))?
+                .execute(PrintStyledContent(tile.to_styled_char()))?;
+        }
+
+        for pos in &path {
+            stdout()
+                .execute(cursor::MoveTo(2 * pos.0 as u16, pos.1 as u16))?
+                .execute(PrintStyledContent(
+                    style(pos.2.to_string()).with(Color::Blue),
+                ))?;
+        }
+
+        {
+            let mut drawn = HashSet::new();
+            for (portal_pos, tile) in map.iter() {
+                if let Tile::Portal(pos, name, z_diff) = tile {
+                    if *z_diff == 1 {
+                        stdout()
+                            .execute(cursor::MoveTo(
+                                (2 * portal_pos.0 - 1) as u16,
+                                portal_pos.1 as u16,
+                            ))?
+                            .execute(PrintStyledContent(
+                                style(name[0] as char).with(Color::Green),
+                            ))?
+                            .execute(cursor::MoveTo(2 * portal_pos.0 as u16, portal_pos.1 as u16))?
+                            .execute(PrintStyledContent(
+                                style(name[1] as char).with(Color::Green),
+                            ))?
+                            .execute(cursor::MoveTo((2 * pos.0 - 1) as u16, pos.1 as u16))?
+                            .execute(PrintStyledContent(
+                                style(name[0].to_ascii_lowercase() as char).with(Color::Green),
+                            ))?
+                            .execute(cursor::MoveTo(2 * pos.0 as u16, pos.1 as u16))?
+                            .execute(PrintStyledContent(
+                                style(name[1].to_ascii_lowercase() as char).with(Color::Green),
+                            ))?;
+                    } else if *z_diff == -1 {
+                        stdout()
+                            .execute(cursor::MoveTo(
+                                (2 * portal_pos.0 - 1) as u16,
+                                portal_pos.1 as u16,
+                            ))?
+                            .execute(PrintStyledContent(style(name[0] as char).with(Color::Red)))?
+                            .execute(cursor::MoveTo(2 * portal_pos.0 as u16, portal_pos.1 as u16))?
+                            .execute(PrintStyledContent(style(name[1] as char).with(Color::Red)))?
+                            .execute(cursor::MoveTo((2 * pos.0 - 1) as u16, pos.1 as u16))?
+                            .execute(PrintStyledContent(
+                                style(name[0].to_ascii_lowercase() as char).with(Color::Red),
+                            ))?
+                            .execute(cursor::MoveTo(2 * pos.0 as u16, pos.1 as u16))?
+                            .execute(PrintStyledContent(
+                                style(name[1].to_ascii_lowercase() as char).with(Color::Red),
+                            ))?;
+
+                        drawn.insert(*name);
+                    }
+                }
+            }
+        }
+
+        {
+            let max_pos = map.bounds().ok_or("Error")?.max;
+            stdout()
+                .execute(cursor::MoveTo(0, max_pos.1 as u16))?
+                .execute(Print('\n'))?;
+        }
+    }
+
+    Ok(path.len() - 1)
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    Ok(run(input, false)?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_map, shortest_path};
+
+    #[test]
+    fn test_20_b() {
+        {
+            let input = "         A           
+         A           
+  #######.#########  
+  #######.........#  
+  #######.#######.#  
+  #######.#######.#  
+  #######.#######.#  
+  #####  B    ###.#  
+BC...##  C    ###.#  
+  ##.##       ###.#  
+  ##...DE  F  ###.#  
+  #####    G  ###.#  
+  #########.#####.#  
+DE..#######...###.#  
+  #.#########.###.#  
+FG..#########.....#  
+  ###########.#####  
+             Z       
+             Z       ";
+
+            let map = parse_map(input).unwrap();
+
+            let start_pos = map
+                .tiles()
+                .find_map(|tile| tile.get_destination_if_name_matches(b"AA"))
+                .unwrap();
+            let end_pos = map
+                .tiles()
+                .find_map(|tile| tile.get_destination_if_name_matches(b"ZZ"))
+                .unwrap();
+
+            let path = shortest_path(start_pos, end_pos, &map).unwrap();
+
+            assert_eq!(path.len() - 1, 26)
+        }
+
+        {
+            let input = "             Z L X W       C                 
+             Z P Q B       K                 
+  ###########.#.#.#.#######.###############  
+  #...#.......#.#.......#.#.......#.#.#...#  
+  ###.#.#.#.#.#.#.#.###.#.#.#######.#.#.###  
+  #.#...#.#.#...#.#.#...#...#...#.#.......#  
+  #.###.#######.###.###.#.###.###.#.#######  
+  #...#.......#.#...#...#.............#...#  
+  #.#########.#######.#.#######.#######.###  
+  #...#.#    F       R I       Z    #.#.#.#  
+  #.###.#    D       E C       H    #.#.#.#  
+  #.#...#                           #...#.#  
+  #.###.#                           #.###.#  
+  #.#....OA                       WB..#.#..ZH
+  #.###.#                           #.#.#.#  
+CJ......#                           #.....#  
+  #######                           #######  
+  #.#....CK                         #......IC
+  #.###.#                           #.###.#  
+  #.....#                           #...#.#  
+  ###.###                           #.#.#.#  
+XF....#.#                         RF..#.#.#  
+  #####.#                           #######  
+  #......CJ                       NM..#...#  
+  ###.#.#                           #.###.#  
+RE....#.#                           #......RF
+  ###.###        X   X       L      #.#.#.#  
+  #.....#        F   Q       P      #.#.#.#  
+  ###.###########.###.#######.#########.###  
+  #.....#...#.....#.......#...#.....#.#...#  
+  #####.#.###.#######.#######.###.###.#.#.#  
+  #.......#.......#.#.#.#.#...#...#...#.#.#  
+  #####.###.#####.#.#.#.#.###.###.#.###.###  
+  #.......#.....#.#...#...............#...#  
+  #############.#.#.###.###################  
+               A O F   N                     
+               A A D   M                     ";
+
+            let map = parse_map(input).unwrap();
+
+            let start_pos = map
+                .tiles()
+                .find_map(|tile| tile.get_destination_if_name_matches(b"AA"))
+                .unwrap();
+            let end_pos = map
+                .tiles()
+                .find_map(|tile| tile.get_destination_if_name_matches(b"ZZ"))
+                .unwrap();
+
+            let path = shortest_path(start_pos, end_pos, &map).unwrap();
+
+            assert_eq!(path.len() - 1, 396)
+        }
+    }
+}
@@ -1,34 +1,34 @@
-use int_comp::IntcodeComputer;
+use day_21_a::FORMULA;
+use springscript::synth::synthesize;
+use springscript::{run, Mode, Script};
+use std::env;
 use std::error::Error;
 use std::fs;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input")?;
+fn arg(name: &str) -> Option<String> {
+    env::args().skip_while(|arg| arg != name).nth(1)
+}
 
-    let program = input
-        .split(",")
-        .map(|v| v.trim().parse::<i64>())
-        .collect::<Result<Vec<_>, _>>()?;
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = input::from_args(21, 'a')?;
 
-    let input = "NOT A T
-NOT T T
-AND B T
-AND C T
-NOT D J
-OR J T
-NOT T J
-WALK
-";
+    let script = match arg("--script") {
+        Some(path) => fs::read_to_string(path)?.parse::<Script>()?,
+        None => synthesize(
+            &arg("--formula").as_deref().unwrap_or(FORMULA).parse()?,
+            Mode::Walk,
+        )?,
+    };
 
-    let mut incode = IntcodeComputer::new(&program);
+    print!("{}", script);
 
-    let output = incode.run(&input.chars().map(|c| c as i64).collect::<Vec<_>>(), None)?;
+    let output = run(&input, &script)?;
 
     for data in output.data() {
         print!("{}", *data as u8 as char);
     }
 
-    println!("");
+    println!();
 
     println!("{}", output.data().last().ok_or("No Output")?);
 
@@ -0,0 +1,13 @@
+use springscript::synth::synthesize;
+use springscript::{run, Mode};
+use std::error::Error;
+
+pub const FORMULA: &str = "!(A & B & C) & D & (H | E & (F | I))";
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    let script = synthesize(&FORMULA.parse()?, Mode::Run)?;
+
+    let output = run(input, &script)?;
+
+    Ok(output.data().last().ok_or("No Output")?.to_string())
+}
@@ -1,42 +1,34 @@
-use int_comp::IntcodeComputer;
+use day_21_b::FORMULA;
+use springscript::synth::synthesize;
+use springscript::{run, Mode, Script};
+use std::env;
 use std::error::Error;
 use std::fs;
 
+fn arg(name: &str) -> Option<String> {
+    env::args().skip_while(|arg| arg != name).nth(1)
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input")?;
-
-    let program = input
-        .split(",")
-        .map(|v| v.trim().parse::<i64>())
-        .collect::<Result<Vec<_>, _>>()?;
-
-    let input = "NOT I T
-NOT F J
-AND J T
-NOT E J
-OR J T
-NOT H J
-AND J T
-NOT A J
-NOT J J
-AND B J
-AND C J
-OR J T
-NOT D J
-OR J T
-NOT T J
-RUN
-";
-
-    let mut incode = IntcodeComputer::new(&program);
-
-    let output = incode.run(&input.chars().map(|c| c as i64).collect::<Vec<_>>(), None)?;
+    let input = input::from_args(21, 'b')?;
+
+    let script = match arg("--script") {
+        Some(path) => fs::read_to_string(path)?.parse::<Script>()?,
+        None => synthesize(
+            &arg("--formula").as_deref().unwrap_or(FORMULA).parse()?,
+            Mode::Run,
+        )?,
+    };
+
+    print!("{}", script);
+
+    let output = run(&input, &script)?;
 
     for data in output.data() {
         print!("{}", *data as u8 as char);
     }
 
-    println!("");
+    println!();
 
     println!("{}", output.data().last().ok_or("No Output")?);
 
@@ -0,0 +1,24 @@
+use network::{Network, SIZE};
+use std::error::Error;
+
+pub fn simulate(input: &str) -> Result<Network, Box<dyn Error>> {
+    let program = input
+        .split(',')
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut network = Network::new(&program, SIZE);
+    network.run_until(|network| network.first_nat_packet().is_some())?;
+
+    Ok(network)
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    let network = simulate(input)?;
+
+    Ok(network
+        .first_nat_packet()
+        .ok_or("No packet sent to 255")?
+        .y
+        .to_string())
+}
@@ -1,58 +1,26 @@
-use int_comp::{IntcodeComputer, IntcodeOutput};
+use day_23_a::simulate;
+use std::env;
 use std::error::Error;
-use std::fs;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input")?;
+    let input = input::from_args(23, 'a')?;
 
-    let program = input
-        .split(",")
-        .map(|v| v.trim().parse::<i64>())
-        .collect::<Result<Vec<_>, _>>()?;
+    let stats = env::args().any(|arg| arg == "--stats");
+    let log_path = env::args().skip_while(|arg| arg != "--log").nth(1);
 
-    let mut computers = Vec::new();
-    let mut queues = Vec::new();
+    let network = simulate(&input)?;
 
-    for i in 0..50 {
-        computers.push(IntcodeComputer::new(&program));
-        queues.push(vec![i]);
+    if stats {
+        print!("{}", network);
     }
 
-    let mut done = false;
-
-    loop {
-        for (i, incode) in computers.iter_mut().enumerate() {
-            let mut input = queues[i].drain(..).collect::<Vec<_>>();
-
-            if input.len() == 0 {
-                input.push(-1);
-            }
-
-            let output = incode.run(&input, Some(3))?;
-
-            match output {
-                IntcodeOutput::Interrupt(out) => {
-                    let address = out[0];
-                    let x = out[1];
-                    let y = out[2];
-
-                    if address == 255 {
-                        println!("255 Y: {}", y);
-                        done = true;
-                        break;
-                    }
+    if let Some(log_path) = log_path {
+        network.export(&log_path)?;
+    }
 
-                    queues[address as usize].push(x);
-                    queues[address as usize].push(y);
-                }
-                _ => continue,
-            }
-        }
+    let packet = network.first_nat_packet().ok_or("No packet sent to 255")?;
 
-        if done {
-            break;
-        }
-    }
+    println!("255 Y: {}", packet.y);
 
     Ok(())
 }
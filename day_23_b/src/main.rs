@@ -1,77 +1,33 @@
-use int_comp::{IntcodeComputer, IntcodeOutput};
+use day_23_b::simulate;
+use std::env;
 use std::error::Error;
-use std::fs;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input")?;
+    let input = input::from_args(23, 'b')?;
 
-    let program = input
-        .split(",")
-        .map(|v| v.trim().parse::<i64>())
-        .collect::<Result<Vec<_>, _>>()?;
+    let stats = env::args().any(|arg| arg == "--stats");
+    let log_path = env::args().skip_while(|arg| arg != "--log").nth(1);
 
-    let mut computers = Vec::new();
-    let mut queues = Vec::new();
-    let mut nat = (0, 0);
-    let mut last_nat = (0, 0);
+    let network = simulate(&input)?;
 
-    for i in 0..50 {
-        computers.push(IntcodeComputer::new(&program));
-        queues.push(vec![i]);
+    if stats {
+        print!("{}", network);
     }
 
-    let mut done = false;
-    let mut idle = false;
-    let mut last_idle;
-
-    loop {
-        last_idle = idle;
-        idle = true;
-
-        for (i, incode) in computers.iter_mut().enumerate() {
-            let mut input = queues[i].drain(..).collect::<Vec<_>>();
-
-            if input.len() == 0 {
-                if i == 0 && last_idle {
-                    if nat == last_nat {
-                        println!("First nat repeate {:?}", nat);
-                        done = true;
-                    }
-
-                    last_nat = nat;
-
-                    input.push(nat.0);
-                    input.push(nat.1);
-                } else {
-                    input.push(-1);
-                }
-            } else {
-                idle = false;
-            }
-
-            let output = incode.run(&input, Some(3))?;
-
-            match output {
-                IntcodeOutput::Interrupt(out) => {
-                    let address = out[0];
-                    let x = out[1];
-                    let y = out[2];
-
-                    if address == 255 {
-                        nat = (x, y);
-                    } else {
-                        queues[address as usize].push(x);
-                        queues[address as usize].push(y);
-                    }
-                }
-                _ => continue,
-            }
-        }
+    if let Some(log_path) = log_path {
+        network.export(&log_path)?;
+    }
 
-        if done {
-            break;
-        }
+    if let Some(packet) = network.first_nat_packet() {
+        println!("255 Y: {}", packet.y);
     }
 
+    println!(
+        "First repeated NAT Y: {}",
+        network
+            .first_repeated_wakeup()
+            .ok_or("NAT never repeated")?
+    );
+
     Ok(())
 }
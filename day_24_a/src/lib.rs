@@ -0,0 +1,157 @@
+use grid::{DenseGrid, TileChar};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+enum Tile {
+    Ground,
+    Bug,
+}
+
+impl TileChar for Tile {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '.' => Some(Tile::Ground),
+            '#' => Some(Tile::Bug),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Ground => '.',
+            Tile::Bug => '#',
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash)]
+struct Map {
+    grid: DenseGrid<Tile>,
+}
+
+impl Map {
+    fn count_adjacent_bugs(&self, pos: (i32, i32)) -> usize {
+        self.grid
+            .neighbors4(pos)
+            .filter(|(_, tile)| **tile == Tile::Bug)
+            .count()
+    }
+
+    fn biodiversity_rating(&self) -> usize {
+        self.grid
+            .tiles()
+            .enumerate()
+            .filter(|(_, tile)| **tile == Tile::Bug)
+            .map(|(i, _)| 2usize.pow(i as u32))
+            .sum()
+    }
+
+    fn next(&self) -> Map {
+        let grid = DenseGrid::from_fn(self.grid.width(), self.grid.height(), |pos| {
+            let adjacent_bugs = self.count_adjacent_bugs(pos);
+
+            match self.grid.get(pos) {
+                Some(Tile::Bug) if adjacent_bugs == 1 => Tile::Bug,
+                Some(Tile::Ground) if adjacent_bugs == 1 || adjacent_bugs == 2 => Tile::Bug,
+                _ => Tile::Ground,
+            }
+        });
+
+        Map { grid }
+    }
+}
+
+impl fmt::Debug for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.grid.to_string().trim_end())
+    }
+}
+
+fn parse_map(input: &str) -> Result<Map, Box<dyn Error>> {
+    let grid = input
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .parse()?;
+
+    Ok(Map { grid })
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    let mut map = parse_map(input)?;
+
+    let mut previous_maps = HashSet::new();
+    previous_maps.insert(map.clone());
+
+    loop {
+        map = map.next();
+
+        if previous_maps.contains(&map) {
+            break;
+        }
+
+        previous_maps.insert(map.clone());
+    }
+
+    Ok(map.biodiversity_rating().to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod tests {
+    use super::parse_map;
+
+    #[test]
+    fn test_map_next() {
+        let input = "....#
+            #..#.
+            #..##
+            ..#..
+            #....";
+
+        let mut map = parse_map(&input).unwrap();
+
+        map = map.next();
+        assert_eq!(
+            format!("{:?}", map),
+            "#..#.
+####.
+###.#
+##.##
+.##.."
+        );
+
+        map = map.next();
+        assert_eq!(
+            format!("{:?}", map),
+            "#####
+....#
+....#
+...#.
+#.###"
+        );
+
+        map = map.next();
+        assert_eq!(
+            format!("{:?}", map),
+            "#....
+####.
+...##
+#.##.
+.##.#"
+        );
+
+        map = map.next();
+        assert_eq!(
+            format!("{:?}", map),
+            "####.
+....#
+##..#
+.....
+##..."
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use grid::{Bounds, DenseGrid, Pos, TileChar};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Tile {
+    Ground,
+    Bug,
+}
+
+impl TileChar for Tile {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '.' | '?' => Some(Tile::Ground),
+            '#' => Some(Tile::Bug),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Ground => '.',
+            Tile::Bug => '#',
+        }
+    }
+}
+
+const MAP_WIDTH: usize = 5;
+const MAP_HEIGHT: usize = 5;
+
+fn positions() -> impl Iterator<Item = Pos> {
+    Bounds::new((0, 0), (MAP_WIDTH as i32 - 1, MAP_HEIGHT as i32 - 1))
+        .positions()
+        .filter(|pos| *pos != (2, 2))
+}
+
+#[derive(Clone, Eq, PartialEq)]
+struct Map {
+    data: HashMap<i32, DenseGrid<Tile>>,
+}
+
+impl Map {
+    fn get_adjacent(&self, x: i32, y: i32, z: i32, from: (i32, i32)) -> Vec<Tile> {
+        if x < 0 {
+            return vec![self.get(1, 2, z - 1)];
+        }
+
+        if x >= MAP_WIDTH as i32 {
+            return vec![self.get(3, 2, z - 1)];
+        }
+
+        if y < 0 {
+            return vec![self.get(2, 1, z - 1)];
+        }
+
+        if y >= MAP_HEIGHT as i32 {
+            return vec![self.get(2, 3, z - 1)];
+        }
+
+        if x == 2 && y == 2 {
+            if from.0 == 1 && from.1 == 2 {
+                let mut res = Vec::new();
+                for y in 0..(MAP_HEIGHT as i32) {
+                    res.push(self.get(0, y, z + 1));
+                }
+                return res;
+            }
+
+            if from.0 == 2 && from.1 == 1 {
+                let mut res = Vec::new();
+                for x in 0..(MAP_WIDTH as i32) {
+                    res.push(self.get(x, 0, z + 1));
+                }
+                return res;
+            }
+
+            if from.0 == 3 && from.1 == 2 {
+                let mut res = Vec::new();
+                for y in 0..(MAP_HEIGHT as i32) {
+                    res.push(self.get(MAP_WIDTH as i32 - 1, y, z + 1));
+                }
+                return res;
+            }
+
+            if from.0 == 2 && from.1 == 3 {
+                let mut res = Vec::new();
+                for x in 0..(MAP_WIDTH as i32) {
+                    res.push(self.get(x, MAP_HEIGHT as i32 - 1, z + 1));
+                }
+                return res;
+            }
+        }
+
+        vec![self.get(x, y, z)]
+    }
+
+    fn get(&self, x: i32, y: i32, z: i32) -> Tile {
+        if x < 0 || y < 0 || x >= MAP_WIDTH as i32 || y >= MAP_HEIGHT as i32 {
+            panic!("Outside");
+        }
+
+        self.data
+            .get(&z)
+            .and_then(|level| level.get((x, y)))
+            .copied()
+            .unwrap_or(Tile::Ground)
+    }
+
+    fn set(&mut self, x: i32, y: i32, z: i32, tile: Tile) {
+        if let Some(level) = self.data.get_mut(&z) {
+            level.set((x, y), tile);
+        };
+    }
+
+    fn count_adjacent_bugs(&self, x: i32, y: i32, z: i32) -> i32 {
+        grid::neighbors4((x, y))
+            .map(|pos| {
+                self.get_adjacent(pos.0, pos.1, z, (x, y))
+                    .iter()
+                    .filter(|tile| **tile == Tile::Bug)
+                    .count()
+            })
+            .sum::<usize>() as i32
+    }
+
+    fn next(&mut self) {
+        {
+            let min_level = *self.data.keys().min().unwrap();
+            let max_level = *self.data.keys().max().unwrap();
+
+            if self.data[&min_level].tiles().any(|tile| *tile == Tile::Bug) {
+                self.data.insert(
+                    min_level - 1,
+                    DenseGrid::new(MAP_WIDTH, MAP_HEIGHT, Tile::Ground),
+                );
+            }
+
+            if self.data[&max_level].tiles().any(|tile| *tile == Tile::Bug) {
+                self.data.insert(
+                    max_level + 1,
+                    DenseGrid::new(MAP_WIDTH, MAP_HEIGHT, Tile::Ground),
+                );
+            }
+        }
+
+        let mut adjacent_bugs = HashMap::new();
+
+        for z in self.data.keys().copied() {
+            for (x, y) in positions() {
+                adjacent_bugs.insert((x, y, z), self.count_adjacent_bugs(x, y, z));
+            }
+        }
+
+        for z in self
+            .data
+            .keys()
+            .copied()
+            .collect::<Vec<_>>()
+            .iter()
+            .copied()
+        {
+            for (x, y) in positions() {
+                let adjacent_bugs = *adjacent_bugs.get(&(x, y, z)).unwrap_or(&0);
+
+                self.set(
+                    x,
+                    y,
+                    z,
+                    match self.get(x, y, z) {
+                        Tile::Ground => {
+                            if adjacent_bugs == 1 || adjacent_bugs == 2 {
+                                Tile::Bug
+                            } else {
+                                Tile::Ground
+                            }
+                        }
+                        Tile::Bug => {
+                            if adjacent_bugs == 1 {
+                                Tile::Bug
+                            } else {
+                                Tile::Ground
+                            }
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let min_level = *self.data.keys().min().unwrap();
+        let max_level = *self.data.keys().max().unwrap();
+
+        for z in min_level..=max_level {
+            writeln!(f, "Level: {}", z)?;
+
+            write!(f, "{}\n\n", self.data[&z].to_string().trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_map(input: &str) -> Result<Map, Box<dyn Error>> {
+    let level = input
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .parse()?;
+
+    let mut map = Map {
+        data: HashMap::new(),
+    };
+
+    map.data.insert(0, level);
+
+    Ok(map)
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    let mut map = parse_map(input)?;
+
+    for _ in 0..200 {
+        map.next();
+    }
+
+    Ok(map
+        .data
+        .values()
+        .map(|level| level.tiles().filter(|tile| **tile == Tile::Bug).count())
+        .sum::<usize>()
+        .to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod tests {
+    use super::{parse_map, Tile};
+
+    #[test]
+    fn test_map_next_2() {
+        let input = "....#
+            #..#.
+            #.?##
+            ..#..
+            #....";
+
+        let mut map = parse_map(&input).unwrap();
+
+        for _ in 0..10 {
+            map.next();
+        }
+
+        assert_eq!(
+            map.data
+                .values()
+                .map(|level| level.tiles().filter(|tile| **tile == Tile::Bug).count())
+                .sum::<usize>(),
+            99
+        );
+    }
+}
@@ -0,0 +1,376 @@
+use int_comp::memory::Dump;
+use int_comp::session::Session;
+use int_comp::{IntcodeComputer, IntcodeOutput};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::iter;
+
+pub const MAX_MACRO_DEPTH: usize = 16;
+
+pub const HELP: &str = "Meta-commands:
+  :save <path>         save the machine state, session and transcript
+  :load <path>         load a saved state
+  :undo                undo the last command
+  :transcript <path>   write the transcript
+  :raw                 show the raw output of the last command
+  :run <path>          run a script of commands
+  :macro <name> <cmd>; <cmd>; ...
+                       define a macro
+  :macros              list macros
+  :help                show this help
+Lines starting with # are comments.";
+
+struct Snapshot {
+    dump: Dump,
+    session: Session,
+    transcript: usize,
+}
+
+pub struct Console {
+    int_comp: IntcodeComputer,
+    session: Session,
+    macros: BTreeMap<String, Vec<String>>,
+    snapshots: Vec<Snapshot>,
+    transcript: String,
+    raw: Vec<i64>,
+    halted: bool,
+}
+
+impl Console {
+    pub fn new(program: &[i64]) -> Console {
+        Console {
+            int_comp: IntcodeComputer::new(program),
+            session: Session::new(),
+            macros: BTreeMap::new(),
+            snapshots: Vec::new(),
+            transcript: String::new(),
+            raw: Vec::new(),
+            halted: false,
+        }
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    pub fn transcript(&self) -> &str {
+        &self.transcript
+    }
+
+    pub fn start(&mut self) -> Result<String, Box<dyn Error>> {
+        self.run(&[])
+    }
+
+    fn run(&mut self, input: &[i64]) -> Result<String, Box<dyn Error>> {
+        let mut input = input;
+        self.raw.clear();
+
+        while !self.halted {
+            match self
+                .int_comp
+                .run_traced(input, Some(1), &mut self.session)?
+            {
+                IntcodeOutput::Halt(data) => {
+                    self.raw.extend(data);
+                    self.halted = true;
+                }
+                IntcodeOutput::Interrupt(data) => {
+                    self.raw.extend(data);
+                    input = &[];
+                }
+                IntcodeOutput::NeedMoreInput => break,
+            }
+        }
+
+        let output = self
+            .raw
+            .iter()
+            .map(|c| *c as u8 as char)
+            .collect::<String>();
+
+        self.transcript.push_str(&output);
+
+        Ok(output)
+    }
+
+    fn send(&mut self, command: &str) -> Result<String, Box<dyn Error>> {
+        if self.halted {
+            return Err("The program has halted".into());
+        }
+
+        self.snapshots.push(Snapshot {
+            dump: self.int_comp.dump(),
+            session: self.session.clone(),
+            transcript: self.transcript.len(),
+        });
+
+        self.transcript.push_str(&format!("{}\n", command));
+
+        let input = command
+            .chars()
+            .chain(iter::once('\n'))
+            .map(|ch| ch as i64)
+            .collect::<Vec<_>>();
+
+        self.run(&input)
+    }
+
+    pub fn execute(&mut self, line: &str) -> Result<String, Box<dyn Error>> {
+        self.execute_nested(line, 0)
+    }
+
+    fn execute_nested(&mut self, line: &str, depth: usize) -> Result<String, Box<dyn Error>> {
+        let line = line.trim();
+
+        if depth > MAX_MACRO_DEPTH {
+            return Err(format!("Macros nested too deep at: {}", line).into());
+        }
+
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(String::new());
+        }
+
+        if let Some(meta) = line.strip_prefix(':') {
+            return self.meta(meta, depth);
+        }
+
+        if let Some(commands) = self.macros.get(line).cloned() {
+            let mut output = String::new();
+
+            for command in commands {
+                output.push_str(&self.execute_nested(&command, depth + 1)?);
+            }
+
+            return Ok(output);
+        }
+
+        self.send(line)
+    }
+
+    fn meta(&mut self, meta: &str, depth: usize) -> Result<String, Box<dyn Error>> {
+        let mut parts = meta.trim().splitn(2, char::is_whitespace);
+        let command = parts.next().unwrap_or("");
+        let argument = parts.next().map(|argument| argument.trim()).unwrap_or("");
+
+        let path = || {
+            if argument.is_empty() {
+                Err(format!(":{} needs a path", command))
+            } else {
+                Ok(argument)
+            }
+        };
+
+        match command {
+            "save" => {
+                let path = path()?;
+                fs::write(path, self.int_comp.dump().to_string())?;
+                self.session.save(&format!("{}.session", path))?;
+                fs::write(format!("{}.transcript", path), &self.transcript)?;
+                Ok(format!("Saved state to {}\n", path))
+            }
+            "load" => {
+                let path = path()?;
+                let dump = fs::read_to_string(path)?.parse::<Dump>()?;
+                let session = Session::load(&format!("{}.session", path))?;
+                let transcript = fs::read_to_string(format!("{}.transcript", path))?;
+
+                self.snapshots.push(Snapshot {
+                    dump: self.int_comp.dump(),
+                    session: self.session.clone(),
+                    transcript: self.transcript.len(),
+                });
+
+                self.int_comp = IntcodeComputer::from_dump(&dump);
+                self.session = session;
+                self.transcript = transcript;
+                self.halted = false;
+
+                Ok(format!("Loaded state from {}\n", path))
+            }
+            "undo" => {
+                let snapshot = self.snapshots.pop().ok_or("Nothing to undo")?;
+
+                self.int_comp = IntcodeComputer::from_dump(&snapshot.dump);
+                self.session = snapshot.session;
+                self.transcript.truncate(snapshot.transcript);
+                self.halted = false;
+
+                Ok("Undone\n".to_string())
+            }
+            "transcript" => {
+                let path = path()?;
+                fs::write(path, &self.transcript)?;
+                Ok(format!("Wrote transcript to {}\n", path))
+            }
+            "raw" => Ok(format!(
+                "{}\n",
+                self.raw
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            )),
+            "run" => {
+                let script = fs::read_to_string(path()?)?;
+                let mut output = String::new();
+
+                for line in script.lines() {
+                    output.push_str(&self.execute_nested(line, depth + 1)?);
+                }
+
+                Ok(output)
+            }
+            "macro" => {
+                let mut parts = argument.splitn(2, char::is_whitespace);
+                let name = parts.next().unwrap_or("");
+
+                if name.is_empty() || name.starts_with(':') {
+                    return Err("Usage: :macro <name> <command>; <command>; ...".into());
+                }
+
+                let commands = parts
+                    .next()
+                    .unwrap_or("")
+                    .split(';')
+                    .map(|command| command.trim())
+                    .filter(|command| !command.is_empty())
+                    .map(|command| command.to_string())
+                    .collect::<Vec<_>>();
+
+                if commands.is_empty() {
+                    self.macros.remove(name);
+                    Ok(format!("Removed macro {}\n", name))
+                } else {
+                    self.macros.insert(name.to_string(), commands);
+                    Ok(format!("Defined macro {}\n", name))
+                }
+            }
+            "macros" => Ok(self
+                .macros
+                .iter()
+                .map(|(name, commands)| format!("{}: {}\n", name, commands.join("; ")))
+                .collect()),
+            "help" => Ok(format!("{}\n", HELP)),
+            _ => Err(format!("Unknown meta-command: :{}", command).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Console;
+    use int_comp::session::Session;
+    use std::env;
+    use std::error::Error;
+    use std::fs;
+    use std::process;
+
+    fn program() -> Vec<i64> {
+        include_str!("../input")
+            .split(',')
+            .map(|v| v.trim().parse::<i64>().unwrap())
+            .collect()
+    }
+
+    fn first_door(output: &str) -> String {
+        output
+            .lines()
+            .skip_while(|line| *line != "Doors here lead:")
+            .nth(1)
+            .and_then(|line| line.strip_prefix("- "))
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_undo_and_macros() -> Result<(), Box<dyn Error>> {
+        let mut console = Console::new(&program());
+        let start = console.start()?;
+        let door = first_door(&start);
+
+        assert_eq!(console.execute("# a comment")?, "");
+
+        let moved = console.execute(&door)?;
+        assert!(moved.contains("=="));
+        assert!(console.transcript().ends_with(&moved));
+
+        console.execute(":undo")?;
+        assert_eq!(console.transcript(), start);
+        assert!(console.execute(":undo").is_err());
+
+        console.execute(&format!(":macro go {}; inv", door))?;
+        let output = console.execute("go")?;
+
+        assert!(output.starts_with(&moved));
+        assert!(output.contains("You aren't carrying any items."));
+        assert_eq!(console.execute(":macros")?, format!("go: {}; inv\n", door));
+
+        console.execute(":macro loop loop")?;
+        assert!(console.execute("loop").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_load_and_raw() -> Result<(), Box<dyn Error>> {
+        let path = env::temp_dir().join(format!("day_25_a_console_state_{}", process::id()));
+        let path = path.to_str().unwrap();
+
+        let mut console = Console::new(&program());
+        let start = console.start()?;
+
+        console.execute(&format!(":save {}", path))?;
+        let moved = console.execute(&first_door(&start))?;
+
+        let raw = console.execute(":raw")?;
+        assert_eq!(raw.trim().split(',').count(), moved.len());
+
+        console.execute(&format!(":load {}", path))?;
+        assert_eq!(console.transcript(), start);
+        assert_eq!(console.execute(&first_door(&start))?, moved);
+
+        remove_state(path)
+    }
+
+    fn remove_state(path: &str) -> Result<(), Box<dyn Error>> {
+        fs::remove_file(path)?;
+        fs::remove_file(format!("{}.session", path))?;
+        fs::remove_file(format!("{}.transcript", path))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_load_and_replay() -> Result<(), Box<dyn Error>> {
+        let path = env::temp_dir().join(format!("day_25_a_console_replay_{}", process::id()));
+        let path = path.to_str().unwrap();
+        let record_path = format!("{}.record", path);
+
+        let mut console = Console::new(&program());
+        let start = console.start()?;
+        console.execute(&first_door(&start))?;
+        console.execute(&format!(":save {}", path))?;
+        console.execute("inv")?;
+
+        let mut resumed = Console::new(&program());
+        resumed.start()?;
+        resumed.execute("inv")?;
+        resumed.execute(&format!(":load {}", path))?;
+        resumed.execute("inv")?;
+
+        assert_eq!(resumed.transcript(), console.transcript());
+
+        resumed.session().save(&record_path)?;
+        let recorded = Session::load(&record_path)?;
+        assert_eq!(&recorded, console.session());
+        assert!(recorded.replay(&program())?.is_none());
+
+        fs::remove_file(&record_path)?;
+        remove_state(path)
+    }
+}
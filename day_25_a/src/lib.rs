@@ -0,0 +1,47 @@
+use adventure::agent::Agent;
+use console::Console;
+use rustyline::Editor;
+use std::error::Error;
+
+pub mod console;
+
+fn parse_program(input: &str) -> Result<Vec<i64>, Box<dyn Error>> {
+    Ok(input
+        .split(',')
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?)
+}
+
+pub fn play(input: &str, script_path: Option<&str>) -> Result<Console, Box<dyn Error>> {
+    let mut console = Console::new(&parse_program(input)?);
+    print!("{}", console.start()?);
+
+    if let Some(script_path) = script_path {
+        print!("{}", console.execute(&format!(":run {}", script_path))?);
+    }
+
+    let mut rl = Editor::<()>::new();
+
+    while !console.is_halted() {
+        match rl.readline(">> ") {
+            Ok(line) => {
+                rl.add_history_entry(line.as_str());
+
+                match console.execute(&line) {
+                    Ok(output) => print!("{}", output),
+                    Err(err) => println!("Error: {}", err),
+                }
+            }
+            Err(err) => {
+                println!("Error: {:?}", err);
+                break;
+            }
+        }
+    }
+
+    Ok(console)
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    Agent::new(&parse_program(input)?)?.solve()
+}
@@ -1,54 +1,23 @@
-use int_comp::{IntcodeComputer, IntcodeOutput};
-use rustyline::Editor;
+use day_25_a::play;
+use std::env;
 use std::error::Error;
 use std::fs;
-use std::iter;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input")?;
+    let input = input::from_args(25, 'a')?;
 
-    let program = input
-        .split(",")
-        .map(|v| v.trim().parse::<i64>())
-        .collect::<Result<Vec<_>, _>>()?;
+    let record_path = env::args().skip_while(|arg| arg != "--record").nth(1);
+    let script_path = env::args().skip_while(|arg| arg != "--script").nth(1);
+    let transcript_path = env::args().skip_while(|arg| arg != "--transcript").nth(1);
 
-    let mut incode = IntcodeComputer::new(&program);
+    let console = play(&input, script_path.as_deref())?;
 
-    let mut rl = Editor::<()>::new();
-    let mut input = Vec::new();
-    let mut run = true;
-
-    while run {
-        let mut got_output = true;
-
-        while got_output {
-            match incode.run(&input.drain(..).collect::<Vec<_>>(), Some(1))? {
-                IntcodeOutput::Halt(_) => {
-                    got_output = false;
-                    run = false;
-                }
-                IntcodeOutput::Interrupt(output) => {
-                    for ch in output.iter().map(|c| *c as u8 as char) {
-                        print!("{}", ch);
-                    }
-                }
-                IntcodeOutput::NeedMoreInput => {
-                    got_output = false;
-                }
-            }
-        }
+    if let Some(record_path) = record_path {
+        console.session().save(&record_path)?;
+    }
 
-        let readline = rl.readline(">> ");
-        match readline {
-            Ok(line) => {
-                rl.add_history_entry(line.as_str());
-                input.extend(line.chars().chain(iter::once('\n')).map(|ch| ch as i64));
-            }
-            Err(err) => {
-                println!("Error: {:?}", err);
-                run = false;
-            }
-        }
+    if let Some(transcript_path) = transcript_path {
+        fs::write(transcript_path, console.transcript())?;
     }
 
     Ok(())
@@ -0,0 +1,18 @@
+use adventure::agent::Agent;
+use std::error::Error;
+
+pub fn solve_agent(input: &str) -> Result<(Agent, String), Box<dyn Error>> {
+    let program = input
+        .split(',')
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut agent = Agent::new(&program)?;
+    let password = agent.solve()?;
+
+    Ok((agent, password))
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    Ok(solve_agent(input)?.1)
+}
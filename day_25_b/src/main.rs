@@ -1,18 +1,16 @@
-use int_comp::IntcodeComputer;
+use day_25_b::solve_agent;
 use std::error::Error;
-use std::fs;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input")?;
+    let input = input::from_args(25, 'b')?;
 
-    let program = input
-        .split(",")
-        .map(|v| v.trim().parse::<i64>())
-        .collect::<Result<Vec<_>, _>>()?;
+    let (agent, password) = solve_agent(&input)?;
 
-    let mut incode = IntcodeComputer::new(&program);
-
-    let _output = incode.run(&[], None)?;
+    println!("Rooms: {}", agent.rooms().count());
+    println!("Dangerous items: {}", agent.dangerous().join(", "));
+    println!("Carried items: {}", agent.inventory().join(", "));
+    println!("Commands: {}", agent.commands().len());
+    println!("Password: {}", password);
 
     Ok(())
 }
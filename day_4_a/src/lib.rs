@@ -0,0 +1,79 @@
+use std::error::Error;
+
+fn segment_num(number: i32) -> Vec<i32> {
+    number
+        .to_string()
+        .chars()
+        .map(|d| d.to_digit(10).unwrap() as i32)
+        .collect()
+}
+
+fn follows_rules(number: i32) -> bool {
+    let arr = segment_num(number);
+
+    if arr.len() != 6 {
+        return false;
+    }
+
+    {
+        let mut found_adjacent = false;
+        let mut last = arr[0];
+
+        for digit in &arr[1..] {
+            if *digit == last {
+                found_adjacent = true;
+                break;
+            }
+            last = *digit;
+        }
+
+        if !found_adjacent {
+            return false;
+        }
+    }
+
+    {
+        let mut is_increasing = true;
+        let mut last = arr[0];
+
+        for digit in &arr[1..] {
+            if *digit < last {
+                is_increasing = false;
+                break;
+            }
+            last = *digit;
+        }
+
+        if !is_increasing {
+            return false;
+        }
+    }
+
+    true
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    let (low, high) = input.trim().split_once('-').ok_or("Expected a range")?;
+
+    let mut num_possible = 0;
+
+    for i in low.parse::<i32>()?..=high.parse::<i32>()? {
+        if follows_rules(i) {
+            num_possible += 1;
+        }
+    }
+
+    Ok(num_possible.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::follows_rules;
+
+    #[test]
+    fn test_follows_rules() {
+        assert!(follows_rules(111111));
+        assert!(!follows_rules(223450));
+        assert!(!follows_rules(123789));
+    }
+}
@@ -0,0 +1,52 @@
+use std::error::Error;
+
+fn parse_images(input: &str, image_len: i32) -> Result<Vec<Vec<i32>>, Box<dyn Error>> {
+    let res = input
+        .trim()
+        .as_bytes()
+        .chunks(image_len as usize)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .map(|byte| {
+                    (*byte as char)
+                        .to_digit(10)
+                        .ok_or("Bad input".to_string())
+                        .map(|n| n as i32)
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(res)
+}
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    let images = parse_images(input, 25 * 6)?;
+
+    let image = images
+        .iter()
+        .min_by_key(|image| image.iter().filter(|d| **d == 0).count())
+        .ok_or("No layers")?;
+
+    let one_count = image.iter().filter(|d| **d == 1).count();
+    let two_count = image.iter().filter(|d| **d == 2).count();
+
+    Ok((one_count * two_count).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_images;
+
+    #[test]
+    fn test() {
+        let input = "123456789012";
+
+        let images = parse_images(input, 3 * 2).unwrap();
+
+        assert_eq!(images.len(), 2);
+        assert_eq!(images[0], [1, 2, 3, 4, 5, 6]);
+        assert_eq!(images[1], [7, 8, 9, 0, 1, 2]);
+    }
+}
@@ -0,0 +1,15 @@
+use int_comp::IntcodeComputer;
+use std::error::Error;
+
+pub fn solve(input: &str) -> Result<String, Box<dyn Error>> {
+    let program = input
+        .split(',')
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut int_comp = IntcodeComputer::new(&program);
+
+    let output = int_comp.run(&[1], None)?;
+
+    Ok(output.data().last().ok_or("No output")?.to_string())
+}
@@ -1,20 +1,9 @@
-use int_comp::IntcodeComputer;
 use std::error::Error;
-use std::fs;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string("input")?;
+    let input = input::from_args(9, 'b')?;
 
-    let program = input
-        .split(",")
-        .map(|v| v.trim().parse::<i64>())
-        .collect::<Result<Vec<_>, _>>()?;
-
-    let mut int_comp = IntcodeComputer::new(&program);
-
-    let output = int_comp.run(&[2], None)?;
-
-    println!("{:?}", output);
+    println!("{}", day_9_b::solve(&input)?);
 
     Ok(())
 }
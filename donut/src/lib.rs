@@ -0,0 +1,190 @@
+use crossterm::style::{style, Color, StyledContent};
+use grid::{Bounds, Pos, SparseGrid};
+use std::error::Error;
+
+pub type Map = SparseGrid<Tile>;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Tile {
+    Wall,
+    Ground,
+    Portal(Pos, [u8; 2], i32),
+}
+
+impl Tile {
+    fn is_ground(&self) -> bool {
+        match self {
+            Tile::Wall => false,
+            Tile::Ground => true,
+            Tile::Portal(..) => false,
+        }
+    }
+
+    fn is_wall(&self) -> Option<Self> {
+        match self {
+            Tile::Wall => Some(Tile::Wall),
+            Tile::Ground => None,
+            Tile::Portal(..) => None,
+        }
+    }
+
+    pub fn get_portal(&self) -> Option<(Pos, [u8; 2], i32)> {
+        match self {
+            Tile::Wall => None,
+            Tile::Ground => None,
+            Tile::Portal(pos, name, z_dir) => Some((*pos, *name, *z_dir)),
+        }
+    }
+
+    fn get_destination(&self, pos: Pos) -> Option<(Pos, i32)> {
+        match self {
+            Tile::Wall => None,
+            Tile::Ground => Some((pos, 0)),
+            Tile::Portal(dest, name, z_dir) => {
+                if name == b"AA" || name == b"ZZ" {
+                    None
+                } else {
+                    Some((*dest, *z_dir))
+                }
+            }
+        }
+    }
+
+    pub fn get_destination_if_name_matches(&self, name: &[u8]) -> Option<Pos> {
+        match self {
+            Tile::Wall => None,
+            Tile::Ground => None,
+            Tile::Portal(dest, portal_name, _) => {
+                if portal_name == name {
+                    Some(*dest)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    pub fn to_styled_char(self) -> StyledContent<char> {
+        match self {
+            Tile::Wall => style('#').with(Color::DarkGrey),
+            Tile::Ground => style('.').with(Color::Grey),
+            Tile::Portal(_, name, _) => style(name[0] as char).with(Color::Green),
+        }
+    }
+}
+
+pub fn neighbors(pos: Pos, map: &Map) -> impl Iterator<Item = (Pos, i32)> + '_ {
+    grid::neighbors4(pos).filter_map(move |candidate| {
+        map.get(candidate)
+            .and_then(|tile| tile.get_destination(candidate))
+    })
+}
+
+pub fn parse_map(input: &str) -> Result<Map, Box<dyn Error>> {
+    let mut map = SparseGrid::parse_with(input, |ch| match ch {
+        '#' => Some(Some(Tile::Wall)),
+        '.' => Some(Some(Tile::Ground)),
+        _ => Some(None),
+    })?;
+    let portal_part = SparseGrid::parse_with(input, |ch| match ch {
+        '#' | '.' | ' ' => Some(None),
+        ch => Some(Some(ch as u8)),
+    })?;
+
+    let walls = Bounds::from_positions(
+        map.iter()
+            .filter_map(|(pos, tile)| tile.is_wall().map(|_| pos)),
+    )
+    .ok_or("Error")?;
+
+    for (pos, part) in portal_part.iter() {
+        for (other_pos, other_part) in portal_part.neighbors4(pos) {
+            let dir = ((pos.0 - other_pos.0).abs(), (pos.1 - other_pos.1).abs());
+
+            let candidates = [
+                ((pos.0 + dir.0), (pos.1 + dir.1)),
+                ((pos.0 - dir.0), (pos.1 - dir.1)),
+                ((other_pos.0 + dir.0), (other_pos.1 + dir.1)),
+                ((other_pos.0 - dir.0), (other_pos.1 - dir.1)),
+            ]
+            .iter()
+            .filter_map(|maybe_dest| map.get(*maybe_dest).map(|tile| (*maybe_dest, *tile)))
+            .collect::<Vec<_>>();
+
+            for (dest_pos, _) in candidates.iter().filter(|(_, tile)| tile.is_ground()) {
+                let (portal_pos, portal_part, far_part) = match (
+                    grid::manhattan(pos, *dest_pos),
+                    grid::manhattan(other_pos, *dest_pos),
+                ) {
+                    (1, 2) => (pos, part, other_part),
+                    (2, 1) => (other_pos, other_part, part),
+                    _ => return Err("Bad map")?,
+                };
+
+                let name = match portal_pos.0 - dest_pos.0 + portal_pos.1 - dest_pos.1 {
+                    1 => [*portal_part, *far_part],
+                    -1 => [*far_part, *portal_part],
+                    _ => return Err("Bad map")?,
+                };
+
+                let z_diff = if walls.is_edge(*dest_pos) { -1 } else { 1 };
+
+                map.insert(portal_pos, Tile::Portal(*dest_pos, name, z_diff));
+            }
+        }
+    }
+
+    let mut portals = map
+        .iter()
+        .filter_map(|(pos, tile)| tile.get_portal().map(|portal| (pos, portal)))
+        .collect::<Vec<_>>();
+
+    for (a, (a_pos, (a_dest, a_name, _))) in portals.clone().into_iter().enumerate() {
+        for (b, (b_pos, (b_dest, b_name, _))) in portals.clone().into_iter().enumerate() {
+            if a_pos != b_pos && a_name == b_name && a > b {
+                (portals[a].1).0 = b_dest;
+                (portals[b].1).0 = a_dest;
+            }
+        }
+    }
+
+    for (pos, (dest, name, z_diff)) in portals {
+        map.insert(pos, Tile::Portal(dest, name, z_diff));
+    }
+
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{neighbors, parse_map, Tile};
+
+    #[test]
+    fn test_parse_map() {
+        let input = "    A    
+    A    
+  ##.##  
+  ##.##  
+BC...#   
+  #.###  
+  #.###  
+  ###.BC 
+  #####  
+";
+
+        let map = parse_map(input).unwrap();
+
+        assert_eq!(
+            map.tiles()
+                .find_map(|tile| tile.get_destination_if_name_matches(b"AA")),
+            Some((4, 2))
+        );
+        assert_eq!(map.get((1, 4)), Some(&Tile::Portal((5, 7), *b"BC", -1)));
+        assert_eq!(map.get((6, 7)), Some(&Tile::Portal((2, 4), *b"BC", 1)));
+
+        let mut steps = neighbors((2, 4), &map).collect::<Vec<_>>();
+        steps.sort();
+        assert_eq!(steps, [((3, 4), 0), ((5, 7), -1)]);
+        assert_eq!(neighbors((4, 2), &map).count(), 1);
+    }
+}
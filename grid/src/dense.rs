@@ -0,0 +1,270 @@
+use super::{parse_cells, Bounds, Pos, TileChar, DIRECTIONS_4, DIRECTIONS_8};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DenseGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> DenseGrid<T> {
+    pub fn new(width: usize, height: usize, tile: T) -> DenseGrid<T> {
+        DenseGrid {
+            width,
+            height,
+            cells: vec![tile; width * height],
+        }
+    }
+}
+
+impl<T> DenseGrid<T> {
+    pub fn from_fn<F: FnMut(Pos) -> T>(width: usize, height: usize, tile: F) -> DenseGrid<T> {
+        DenseGrid {
+            width,
+            height,
+            cells: (0..height as i32)
+                .flat_map(|y| (0..width as i32).map(move |x| (x, y)))
+                .map(tile)
+                .collect(),
+        }
+    }
+
+    pub fn parse_with<F>(input: &str, tile: F) -> Result<DenseGrid<T>, Box<dyn Error>>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let width = input
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let height = input.lines().count();
+
+        if input.lines().any(|line| line.chars().count() != width) {
+            return Err("All rows must have the same width".into());
+        }
+
+        Ok(DenseGrid {
+            width,
+            height,
+            cells: parse_cells(input, tile)?
+                .into_iter()
+                .map(|(_, tile)| tile)
+                .collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        Bounds::new((0, 0), (self.width as i32 - 1, self.height as i32 - 1))
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.0 >= 0 && pos.1 >= 0 && (pos.0 as usize) < self.width && (pos.1 as usize) < self.height
+    }
+
+    pub fn index(&self, pos: Pos) -> Option<usize> {
+        if self.contains(pos) {
+            Some(pos.0 as usize + pos.1 as usize * self.width)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index(pos).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index(pos).map(move |index| &mut self.cells[index])
+    }
+
+    pub fn set(&mut self, pos: Pos, tile: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, tile))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        self.bounds().positions()
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        DIRECTIONS_4
+            .iter()
+            .map(move |(dx, dy)| (pos.0 + dx, pos.1 + dy))
+            .filter_map(move |pos| self.get(pos).map(|tile| (pos, tile)))
+    }
+
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        DIRECTIONS_8
+            .iter()
+            .map(move |(dx, dy)| (pos.0 + dx, pos.1 + dy))
+            .filter_map(move |pos| self.get(pos).map(|tile| (pos, tile)))
+    }
+
+    pub fn find<F: Fn(&T) -> bool>(&self, predicate: F) -> Option<Pos> {
+        self.iter()
+            .find(|(_, tile)| predicate(tile))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, tile: F) -> DenseGrid<U> {
+        DenseGrid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(tile).collect(),
+        }
+    }
+}
+
+impl<T: Clone> DenseGrid<T> {
+    fn remap<F: Fn(Pos) -> Pos>(&self, width: usize, height: usize, source: F) -> DenseGrid<T> {
+        DenseGrid::from_fn(width, height, |pos| {
+            let index = self.index(source(pos)).unwrap();
+            self.cells[index].clone()
+        })
+    }
+
+    pub fn rotate_clockwise(&self) -> DenseGrid<T> {
+        let height = self.height as i32;
+        self.remap(self.height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> DenseGrid<T> {
+        let width = self.width as i32;
+        self.remap(self.height, self.width, |(x, y)| (width - 1 - y, x))
+    }
+
+    pub fn flip_horizontal(&self) -> DenseGrid<T> {
+        let width = self.width as i32;
+        self.remap(self.width, self.height, |(x, y)| (width - 1 - x, y))
+    }
+
+    pub fn flip_vertical(&self) -> DenseGrid<T> {
+        let height = self.height as i32;
+        self.remap(self.width, self.height, |(x, y)| (x, height - 1 - y))
+    }
+}
+
+impl<T: TileChar> FromStr for DenseGrid<T> {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        DenseGrid::parse_with(input, T::from_char)
+    }
+}
+
+impl<T: TileChar> fmt::Display for DenseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().map(T::to_char).collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DenseGrid;
+    use crate::TileChar;
+
+    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+    enum Tile {
+        Wall,
+        Open,
+    }
+
+    impl TileChar for Tile {
+        fn from_char(ch: char) -> Option<Self> {
+            match ch {
+                '#' => Some(Tile::Wall),
+                '.' => Some(Tile::Open),
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            match self {
+                Tile::Wall => '#',
+                Tile::Open => '.',
+            }
+        }
+    }
+
+    const MAP: &str = "##.\n#..\n";
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = MAP.parse::<DenseGrid<Tile>>().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((2, 0)), Some(&Tile::Open));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+        assert_eq!(grid.to_string(), MAP);
+
+        assert!("#x".parse::<DenseGrid<Tile>>().is_err());
+        assert!("##\n#".parse::<DenseGrid<Tile>>().is_err());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let mut grid = MAP.parse::<DenseGrid<Tile>>().unwrap();
+
+        assert_eq!(
+            grid.neighbors4((0, 0))
+                .map(|(pos, _)| pos)
+                .collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+        assert_eq!(grid.find(|tile| *tile == Tile::Open), Some((2, 0)));
+
+        assert_eq!(grid.set((2, 0), Tile::Wall), Some(Tile::Open));
+        assert_eq!(grid.set((5, 5), Tile::Wall), None);
+        assert_eq!(grid.find(|tile| *tile == Tile::Open), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = "abc\ndef".parse::<DenseGrid<char>>().unwrap();
+
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+        assert_eq!(
+            grid.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            grid
+        );
+        assert_eq!(
+            grid.map(|ch| ch.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use std::error::Error;
+
+pub mod dense;
+pub mod sparse;
+
+pub use dense::DenseGrid;
+pub use sparse::SparseGrid;
+
+pub type Pos = (i32, i32);
+
+pub const DIRECTIONS_4: [Pos; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+pub const DIRECTIONS_8: [Pos; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+pub fn neighbors4(pos: Pos) -> impl Iterator<Item = Pos> {
+    DIRECTIONS_4
+        .iter()
+        .map(move |(dx, dy)| (pos.0 + dx, pos.1 + dy))
+}
+
+pub fn neighbors8(pos: Pos) -> impl Iterator<Item = Pos> {
+    DIRECTIONS_8
+        .iter()
+        .map(move |(dx, dy)| (pos.0 + dx, pos.1 + dy))
+}
+
+pub fn manhattan(a: Pos, b: Pos) -> i32 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+pub trait TileChar: Sized {
+    fn from_char(ch: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+impl TileChar for char {
+    fn from_char(ch: char) -> Option<Self> {
+        Some(ch)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Bounds {
+    pub min: Pos,
+    pub max: Pos,
+}
+
+impl Bounds {
+    pub fn new(min: Pos, max: Pos) -> Bounds {
+        Bounds { min, max }
+    }
+
+    pub fn from_positions<I: IntoIterator<Item = Pos>>(positions: I) -> Option<Bounds> {
+        let mut positions = positions.into_iter();
+        let first = positions.next()?;
+
+        Some(positions.fold(Bounds::new(first, first), |bounds, pos| bounds.include(pos)))
+    }
+
+    pub fn include(self, pos: Pos) -> Bounds {
+        Bounds {
+            min: (self.min.0.min(pos.0), self.min.1.min(pos.1)),
+            max: (self.max.0.max(pos.0), self.max.1.max(pos.1)),
+        }
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.0 >= self.min.0 && pos.0 <= self.max.0 && pos.1 >= self.min.1 && pos.1 <= self.max.1
+    }
+
+    pub fn is_edge(&self, pos: Pos) -> bool {
+        self.contains(pos)
+            && (pos.0 == self.min.0
+                || pos.0 == self.max.0
+                || pos.1 == self.min.1
+                || pos.1 == self.max.1)
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.0 - self.min.0 + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.1 - self.min.1 + 1) as usize
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let Bounds { min, max } = *self;
+        (min.1..=max.1).flat_map(move |y| (min.0..=max.0).map(move |x| (x, y)))
+    }
+}
+
+pub fn parse_cells<T, F>(input: &str, mut tile: F) -> Result<Vec<(Pos, T)>, Box<dyn Error>>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    let mut cells = Vec::new();
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, ch) in line.chars().enumerate() {
+            match tile(ch) {
+                Some(tile) => cells.push(((x as i32, y as i32), tile)),
+                None => return Err(format!("Unknown tile {:?} at {},{}", ch, x, y).into()),
+            }
+        }
+    }
+
+    Ok(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{manhattan, neighbors4, neighbors8, parse_cells, Bounds};
+
+    #[test]
+    fn test_neighbors() {
+        assert_eq!(
+            neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(0, -1), (1, 0), (0, 1), (-1, 0)]
+        );
+        assert_eq!(neighbors8((2, 2)).count(), 8);
+        assert!(neighbors8((2, 2)).all(|pos| manhattan(pos, (2, 2)) <= 2));
+    }
+
+    #[test]
+    fn test_bounds() {
+        let bounds = Bounds::from_positions(vec![(1, 2), (-1, 5), (3, 0)]).unwrap();
+
+        assert_eq!(bounds, Bounds::new((-1, 0), (3, 5)));
+        assert_eq!((bounds.width(), bounds.height()), (5, 6));
+        assert!(bounds.contains((0, 0)));
+        assert!(!bounds.contains((4, 0)));
+        assert!(bounds.is_edge((3, 3)));
+        assert!(!bounds.is_edge((2, 3)));
+        assert_eq!(bounds.positions().count(), 30);
+        assert_eq!(bounds.positions().nth(1), Some((0, 0)));
+        assert_eq!(Bounds::from_positions(Vec::new()), None);
+    }
+
+    #[test]
+    fn test_parse_cells() {
+        let cells = parse_cells("#.\n.#", |ch| Some(ch == '#')).unwrap();
+
+        assert_eq!(
+            cells,
+            [
+                ((0, 0), true),
+                ((1, 0), false),
+                ((0, 1), false),
+                ((1, 1), true)
+            ]
+        );
+        assert!(parse_cells("#x", |ch| if ch == '#' { Some(()) } else { None }).is_err());
+    }
+}
@@ -0,0 +1,234 @@
+use super::{parse_cells, Bounds, Pos, TileChar, DIRECTIONS_4, DIRECTIONS_8};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+
+    pub fn parse_with<F>(input: &str, tile: F) -> Result<SparseGrid<T>, Box<dyn Error>>
+    where
+        F: FnMut(char) -> Option<Option<T>>,
+    {
+        Ok(SparseGrid {
+            cells: parse_cells(input, tile)?
+                .into_iter()
+                .filter_map(|(pos, tile)| tile.map(|tile| (pos, tile)))
+                .collect(),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn bounds(&self) -> Option<Bounds> {
+        Bounds::from_positions(self.cells.keys().copied())
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn insert(&mut self, pos: Pos, tile: T) -> Option<T> {
+        self.cells.insert(pos, tile)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(pos, tile)| (*pos, tile))
+    }
+
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        DIRECTIONS_4
+            .iter()
+            .map(move |(dx, dy)| (pos.0 + dx, pos.1 + dy))
+            .filter_map(move |pos| self.get(pos).map(|tile| (pos, tile)))
+    }
+
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        DIRECTIONS_8
+            .iter()
+            .map(move |(dx, dy)| (pos.0 + dx, pos.1 + dy))
+            .filter_map(move |pos| self.get(pos).map(|tile| (pos, tile)))
+    }
+
+    pub fn find<F: Fn(&T) -> bool>(&self, predicate: F) -> Option<Pos> {
+        self.iter()
+            .filter(|(_, tile)| predicate(tile))
+            .map(|(pos, _)| pos)
+            .min_by_key(|pos| (pos.1, pos.0))
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    fn remap<F: Fn(Pos, Bounds) -> Pos>(&self, target: F) -> SparseGrid<T> {
+        let bounds = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return SparseGrid::new(),
+        };
+
+        SparseGrid {
+            cells: self
+                .iter()
+                .map(|(pos, tile)| {
+                    let relative = (pos.0 - bounds.min.0, pos.1 - bounds.min.1);
+                    let moved = target(relative, bounds);
+                    (
+                        (bounds.min.0 + moved.0, bounds.min.1 + moved.1),
+                        tile.clone(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> SparseGrid<T> {
+        self.remap(|(x, y), bounds| (bounds.height() as i32 - 1 - y, x))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> SparseGrid<T> {
+        self.remap(|(x, y), bounds| (y, bounds.width() as i32 - 1 - x))
+    }
+
+    pub fn flip_horizontal(&self) -> SparseGrid<T> {
+        self.remap(|(x, y), bounds| (bounds.width() as i32 - 1 - x, y))
+    }
+
+    pub fn flip_vertical(&self) -> SparseGrid<T> {
+        self.remap(|(x, y), bounds| (x, bounds.height() as i32 - 1 - y))
+    }
+}
+
+impl<T> std::iter::FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: TileChar> FromStr for SparseGrid<T> {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        SparseGrid::parse_with(input, |ch| match ch {
+            ' ' => Some(None),
+            ch => T::from_char(ch).map(Some),
+        })
+    }
+}
+
+impl<T: TileChar> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bounds = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+
+        for y in bounds.min.1..=bounds.max.1 {
+            let row = (bounds.min.0..=bounds.max.0)
+                .map(|x| self.get((x, y)).map_or(' ', T::to_char))
+                .collect::<String>();
+
+            writeln!(f, "{}", row.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SparseGrid;
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = "  #\n# .\n".parse::<SparseGrid<char>>().unwrap();
+
+        assert_eq!(grid.len(), 3);
+        assert!(!grid.contains((0, 0)));
+        assert_eq!(grid.get((2, 1)), Some(&'.'));
+        assert_eq!(grid.to_string(), "  #\n# .\n");
+        assert_eq!(grid.find(|ch| *ch == '#'), Some((2, 0)));
+
+        let walls = SparseGrid::parse_with("#.#", |ch| match ch {
+            '#' => Some(Some(())),
+            '.' => Some(None),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(walls.positions().count(), 2);
+        assert_eq!(walls.tiles().count(), 2);
+        assert!(SparseGrid::<()>::parse_with("x", |_| None).is_err());
+    }
+
+    #[test]
+    fn test_neighbors_and_bounds() {
+        let mut grid = SparseGrid::new();
+        grid.insert((-2, 3), 'a');
+        grid.insert((-1, 3), 'b');
+        grid.insert((-1, 4), 'c');
+
+        let bounds = grid.bounds().unwrap();
+        assert_eq!((bounds.min, bounds.max), ((-2, 3), (-1, 4)));
+        assert_eq!(grid.neighbors4((-1, 3)).count(), 2);
+        assert_eq!(grid.neighbors8((-2, 4)).count(), 3);
+        assert_eq!(grid.remove((-2, 3)), Some('a'));
+        assert_eq!(SparseGrid::<char>::new().bounds(), None);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = [((10, 20), 'a'), ((12, 20), 'c'), ((10, 21), 'd')]
+            .iter()
+            .copied()
+            .collect::<SparseGrid<char>>();
+
+        assert_eq!(grid.to_string(), "a c\nd\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\n\n c\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "c\n\nad\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "c a\n  d\n");
+        assert_eq!(grid.flip_vertical().to_string(), "d\na c\n");
+        assert_eq!(grid.rotate_clockwise().bounds().unwrap().min, (10, 20));
+    }
+}
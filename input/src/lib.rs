@@ -0,0 +1,174 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+pub const INPUT_DIR: &str = "AOC_INPUT_DIR";
+pub const STDIN: &str = "-";
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default()
+}
+
+fn file_names(day: u32, part: char) -> [String; 2] {
+    [
+        format!("day{:02}{}.txt", day, part),
+        format!("day{:02}.txt", day),
+    ]
+}
+
+pub fn candidates(day: u32, part: char, dir: Option<&Path>) -> Vec<PathBuf> {
+    let workspace = workspace_dir();
+    let mut candidates = Vec::new();
+
+    for dir in dir
+        .into_iter()
+        .chain(Some(workspace.join("inputs").as_path()))
+    {
+        candidates.extend(file_names(day, part).iter().map(|name| dir.join(name)));
+    }
+
+    candidates.push(
+        workspace
+            .join(format!("day_{}_{}", day, part))
+            .join("input"),
+    );
+    candidates
+}
+
+pub fn locate(day: u32, part: char, path: Option<&str>) -> Result<Source, Box<dyn Error>> {
+    match path {
+        Some(STDIN) => return Ok(Source::Stdin),
+        Some(path) => return Ok(Source::File(path.into())),
+        None => {}
+    }
+
+    let dir = env::var_os(INPUT_DIR).map(PathBuf::from);
+    let candidates = candidates(day, part, dir.as_deref());
+
+    match candidates.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(Source::File(path.clone())),
+        None => Err(format!(
+            "No input for day {} part {}, pass --input <path> or - for stdin, or add one of:\n{}",
+            day,
+            part,
+            candidates
+                .iter()
+                .map(|path| format!("  {}", path.display()))
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+        .into()),
+    }
+}
+
+pub fn normalize(input: &str) -> &str {
+    input.trim_end_matches(&['\n', '\r'][..])
+}
+
+pub fn read(source: &Source) -> Result<String, Box<dyn Error>> {
+    let input = match source {
+        Source::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            input
+        }
+        Source::File(path) => fs::read_to_string(path)
+            .map_err(|err| format!("Could not read input {}: {}", path.display(), err))?,
+    };
+
+    if input.trim().is_empty() {
+        return Err(format!("Input from {} is empty", source).into());
+    }
+
+    Ok(normalize(&input).to_string())
+}
+
+pub fn load(day: u32, part: char, path: Option<&str>) -> Result<String, Box<dyn Error>> {
+    read(&locate(day, part, path)?)
+}
+
+pub fn path_arg() -> Option<String> {
+    env::args().skip_while(|arg| arg != "--input").nth(1)
+}
+
+pub fn from_args(day: u32, part: char) -> Result<String, Box<dyn Error>> {
+    load(day, part, path_arg().as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{candidates, load, locate, normalize, read, Source, STDIN};
+    use std::env;
+    use std::error::Error;
+    use std::fs;
+    use std::process;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("1,2,3\n"), "1,2,3");
+        assert_eq!(normalize("1\r\n2\r\n\n"), "1\r\n2");
+        assert_eq!(normalize("  #.#  \n"), "  #.#  ");
+    }
+
+    #[test]
+    fn test_locate() -> Result<(), Box<dyn Error>> {
+        assert_eq!(locate(1, 'a', Some(STDIN))?, Source::Stdin);
+        assert_eq!(
+            locate(1, 'a', Some("my_input"))?,
+            Source::File("my_input".into())
+        );
+
+        let dir = env::temp_dir().join(format!("input_test_locate_{}", process::id()));
+        fs::create_dir_all(&dir)?;
+
+        let paths = candidates(14, 'b', Some(&dir));
+        assert!(paths[0].ends_with("day14b.txt"));
+        assert!(paths[1].ends_with("day14.txt"));
+        assert!(paths[2].ends_with("inputs/day14b.txt"));
+        assert!(paths.last().unwrap().ends_with("day_14_b/input"));
+
+        fs::write(dir.join("day14.txt"), "10 ORE => 1 FUEL\n\n")?;
+        let source = Source::File(dir.join("day14.txt"));
+        assert_eq!(read(&source)?, "10 ORE => 1 FUEL");
+
+        fs::write(dir.join("day14.txt"), "\n")?;
+        assert!(read(&source).is_err());
+
+        fs::remove_dir_all(&dir)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load() -> Result<(), Box<dyn Error>> {
+        let input = load(1, 'a', None)?;
+        assert!(!input.ends_with('\n'));
+        assert_eq!(input.lines().count(), 100);
+
+        let err = load(1, 'a', Some("no_such_input")).unwrap_err();
+        assert!(err.to_string().contains("no_such_input"));
+
+        Ok(())
+    }
+}
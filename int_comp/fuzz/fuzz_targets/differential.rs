@@ -0,0 +1,19 @@
+#![no_main]
+use int_comp::fuzz;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() < 8 {
+        return;
+    }
+
+    let (seed, rest) = data.split_at(8);
+    let mut seed_bytes = [0; 8];
+    seed_bytes.copy_from_slice(seed);
+
+    let mut rng = fuzz::Rng::new(u64::from_le_bytes(seed_bytes));
+    let program = fuzz::generate_program(&mut rng, 8 + rest.len());
+    let input = fuzz::program_from_bytes(rest);
+
+    fuzz::differential(&program, &input).unwrap();
+});
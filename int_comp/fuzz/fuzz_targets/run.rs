@@ -0,0 +1,9 @@
+#![no_main]
+use int_comp::fuzz;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let program = fuzz::program_from_bytes(data);
+
+    fuzz::check(&program, &[0, 1, -1]).unwrap();
+});
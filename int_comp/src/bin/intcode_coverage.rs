@@ -0,0 +1,49 @@
+use int_comp::coverage::Coverage;
+use int_comp::{parse_program, IntcodeComputer};
+use std::env;
+use std::error::Error;
+use std::fs;
+
+fn read_input(path: &str) -> Result<Vec<i64>, Box<dyn Error>> {
+    let input = fs::read_to_string(path)?;
+
+    match parse_program(&input) {
+        Ok(values) => Ok(values),
+        Err(_) => Ok(input.bytes().map(|b| b as i64).collect()),
+    }
+}
+
+fn collect(program: &[i64], input: &[i64]) -> Result<Coverage, Box<dyn Error>> {
+    let mut coverage = Coverage::new();
+    IntcodeComputer::new(program).run_traced(input, None, &mut coverage)?;
+    Ok(coverage)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    if args.len() < 2 || args.len() > 3 {
+        return Err("Usage: intcode_coverage <program> <input> [<other input>]".into());
+    }
+
+    let program = parse_program(&fs::read_to_string(&args[0])?)?;
+    let first = collect(&program, &read_input(&args[1])?)?;
+
+    match args.get(2) {
+        Some(path) => {
+            let second = collect(&program, &read_input(path)?)?;
+
+            print!("{}", first.annotate_diff(&second, &program));
+            println!();
+            println!("{}: {}", args[1], first.summary(&program));
+            println!("{}: {}", path, second.summary(&program));
+        }
+        None => {
+            print!("{}", first.annotate(&program));
+            println!();
+            println!("{}", first.summary(&program));
+        }
+    }
+
+    Ok(())
+}
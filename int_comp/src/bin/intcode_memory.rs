@@ -0,0 +1,74 @@
+use int_comp::memory::{self, Dump, Patch};
+use int_comp::{parse_program, IntcodeComputer};
+use std::env;
+use std::error::Error;
+use std::fs;
+
+const USAGE: &str = "Usage:
+  intcode_memory dump <program> [<input>] [--steps <count>]
+  intcode_memory diff <dump> <dump>
+  intcode_memory patch <dump> <patch>";
+
+fn read_input(path: &str) -> Result<Vec<i64>, Box<dyn Error>> {
+    let input = fs::read_to_string(path)?;
+
+    match parse_program(&input) {
+        Ok(values) => Ok(values),
+        Err(_) => Ok(input.bytes().map(|b| b as i64).collect()),
+    }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+
+    let steps = match args.iter().position(|arg| arg == "--steps") {
+        Some(index) => {
+            let steps = args.get(index + 1).ok_or(USAGE)?.parse::<u64>()?;
+            args.drain(index..index + 2);
+            Some(steps)
+        }
+        None => None,
+    };
+
+    let args = args.iter().map(|arg| arg.as_str()).collect::<Vec<_>>();
+
+    match args.as_slice() {
+        ["dump", program] | ["dump", program, _] => {
+            let dump = fs::read_to_string(program)?.parse::<Dump>()?;
+            let input = match args.get(2) {
+                Some(path) => read_input(path)?,
+                None => Vec::new(),
+            };
+
+            let mut intcode = IntcodeComputer::from_dump(&dump);
+            match steps {
+                Some(steps) => {
+                    intcode.run_steps(&input, steps)?;
+                }
+                None => {
+                    intcode.run(&input, None)?;
+                }
+            }
+
+            print!("{}", intcode.dump());
+        }
+        ["diff", before, after] => {
+            let before = fs::read_to_string(before)?.parse::<Dump>()?;
+            let after = fs::read_to_string(after)?.parse::<Dump>()?;
+
+            print!("{}", memory::format_diff(&before, &after));
+        }
+        ["patch", dump, patch] => {
+            let dump = fs::read_to_string(dump)?.parse::<Dump>()?;
+            let patch = fs::read_to_string(patch)?.parse::<Patch>()?;
+
+            let mut intcode = IntcodeComputer::from_dump(&dump);
+            intcode.apply_patch(&patch)?;
+
+            print!("{}", intcode.dump());
+        }
+        _ => return Err(USAGE.into()),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use int_comp::profile::Profile;
+use int_comp::{parse_program, IntcodeComputer};
+use std::env;
+use std::error::Error;
+use std::fs;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1);
+
+    let mut program_path = None;
+    let mut input = Vec::new();
+    let mut folded_path = None;
+    let mut top = 20;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = parse_program(&args.next().ok_or("Missing value for --input")?)?,
+            "--folded" => folded_path = Some(args.next().ok_or("Missing value for --folded")?),
+            "--top" => top = args.next().ok_or("Missing value for --top")?.parse()?,
+            _ => program_path = Some(arg),
+        }
+    }
+
+    let program_path = program_path.ok_or(
+        "Usage: intcode_profile <program> [--input 1,2,3] [--folded out.folded] [--top N]",
+    )?;
+    let program = parse_program(&fs::read_to_string(program_path)?)?;
+
+    let mut profile = Profile::new();
+    let output = IntcodeComputer::new(&program).run_traced(&input, None, &mut profile)?;
+
+    println!("{:?}", output);
+    println!();
+    print!("{}", profile.report(top));
+
+    if let Some(folded_path) = folded_path {
+        fs::write(folded_path, profile.folded_stacks())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use int_comp::parse_program;
+use int_comp::session::Session;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::process;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    if args.len() != 2 {
+        return Err("Usage: intcode_replay <program> <session>".into());
+    }
+
+    let program = parse_program(&fs::read_to_string(&args[0])?)?;
+
+    let session = Session::load(&args[1])?;
+
+    match session.replay(&program)? {
+        Some(divergence) => {
+            println!("{}", divergence);
+            process::exit(1);
+        }
+        None => println!("Replayed {} events", session.events.len()),
+    }
+
+    Ok(())
+}
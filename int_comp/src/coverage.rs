@@ -0,0 +1,192 @@
+use super::disasm;
+use super::Trace;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Coverage {
+    pub executed: BTreeMap<usize, u64>,
+    pub branches: BTreeMap<usize, (u64, u64)>,
+}
+
+impl Trace for Coverage {
+    fn instruction(&mut self, pc: usize, _instruction: i64) {
+        *self.executed.entry(pc).or_insert(0) += 1;
+    }
+
+    fn branch(&mut self, pc: usize, taken: bool) {
+        let branch = self.branches.entry(pc).or_insert((0, 0));
+
+        if taken {
+            branch.0 += 1;
+        } else {
+            branch.1 += 1;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageDiff {
+    pub only_first: BTreeSet<usize>,
+    pub only_second: BTreeSet<usize>,
+    pub branches: BTreeMap<usize, (String, String)>,
+}
+
+fn directions(branch: Option<&(u64, u64)>) -> String {
+    match branch {
+        Some((taken, not_taken)) => {
+            let mut directions = String::new();
+            if *taken > 0 {
+                directions.push('T');
+            }
+            if *not_taken > 0 {
+                directions.push('F');
+            }
+            directions
+        }
+        None => "-".to_string(),
+    }
+}
+
+fn lines(program: &[i64], executed: &BTreeSet<usize>) -> Vec<(usize, String)> {
+    let mut lines = Vec::new();
+    let mut address = 0;
+
+    while address < program.len() {
+        let (mut text, mut len) = disasm::disassemble(program, address);
+
+        if !executed.contains(&address) && executed.range(address + 1..address + len).count() > 0 {
+            text = format!("data {}", program[address]);
+            len = 1;
+        }
+
+        lines.push((address, text));
+        address += len;
+    }
+
+    lines
+}
+
+impl Coverage {
+    pub fn new() -> Coverage {
+        Coverage::default()
+    }
+
+    fn addresses(&self) -> BTreeSet<usize> {
+        self.executed.keys().copied().collect()
+    }
+
+    pub fn summary(&self, program: &[i64]) -> String {
+        let executed = self.addresses();
+
+        let lines = lines(program, &executed);
+
+        let instructions = lines
+            .iter()
+            .filter(|(address, text)| executed.contains(address) || !text.starts_with("data"))
+            .count();
+
+        let branches = lines
+            .iter()
+            .filter(|(_, text)| text.starts_with("jt") || text.starts_with("jf"))
+            .count();
+
+        let full_branches = self
+            .branches
+            .values()
+            .filter(|(taken, not_taken)| *taken > 0 && *not_taken > 0)
+            .count();
+
+        format!(
+            "Instructions: {}/{}, branches: {} both ways, {} one way, {} total",
+            executed.len(),
+            instructions,
+            full_branches,
+            self.branches.len() - full_branches,
+            branches
+        )
+    }
+
+    pub fn diff(&self, other: &Coverage) -> CoverageDiff {
+        let first = self.addresses();
+        let second = other.addresses();
+
+        let branches = self
+            .branches
+            .keys()
+            .chain(other.branches.keys())
+            .filter_map(|pc| {
+                let first = directions(self.branches.get(pc));
+                let second = directions(other.branches.get(pc));
+
+                if first != second {
+                    Some((*pc, (first, second)))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        CoverageDiff {
+            only_first: first.difference(&second).copied().collect(),
+            only_second: second.difference(&first).copied().collect(),
+            branches,
+        }
+    }
+
+    pub fn annotate(&self, program: &[i64]) -> String {
+        let mut annotated = String::new();
+
+        for (address, text) in lines(program, &self.addresses()) {
+            let count = self.executed.get(&address).copied().unwrap_or(0);
+            let marker = if count > 0 { '*' } else { ' ' };
+
+            write!(
+                annotated,
+                "{} {:>6} {:>10}  {}",
+                marker, address, count, text
+            )
+            .unwrap();
+
+            if let Some(branch) = self.branches.get(&address) {
+                write!(annotated, "    ; T:{} F:{}", branch.0, branch.1).unwrap();
+            }
+
+            writeln!(annotated).unwrap();
+        }
+
+        annotated
+    }
+
+    pub fn annotate_diff(&self, other: &Coverage, program: &[i64]) -> String {
+        let mut annotated = String::new();
+        let diff = self.diff(other);
+        let executed = self
+            .addresses()
+            .union(&other.addresses())
+            .copied()
+            .collect();
+
+        for (address, text) in lines(program, &executed) {
+            let marker = if diff.only_first.contains(&address) {
+                '-'
+            } else if diff.only_second.contains(&address) {
+                '+'
+            } else if executed.contains(&address) {
+                '*'
+            } else {
+                ' '
+            };
+
+            write!(annotated, "{} {:>6}  {}", marker, address, text).unwrap();
+
+            if let Some((first, second)) = diff.branches.get(&address) {
+                write!(annotated, "    ; {} -> {}", first, second).unwrap();
+            }
+
+            writeln!(annotated).unwrap();
+        }
+
+        annotated
+    }
+}
@@ -0,0 +1,82 @@
+pub fn mnemonic(opcode: i64) -> Option<&'static str> {
+    Some(match opcode {
+        1 => "add",
+        2 => "mul",
+        3 => "in",
+        4 => "out",
+        5 => "jt",
+        6 => "jf",
+        7 => "lt",
+        8 => "eq",
+        9 => "rbo",
+        99 => "halt",
+        _ => return None,
+    })
+}
+
+pub fn parameter_count(opcode: i64) -> usize {
+    match opcode {
+        1 | 2 | 7 | 8 => 3,
+        5 | 6 => 2,
+        3 | 4 | 9 => 1,
+        _ => 0,
+    }
+}
+
+fn format_parameter(value: i64, mode: i64) -> Option<String> {
+    Some(match mode {
+        0 => format!("[{}]", value),
+        1 => format!("{}", value),
+        2 if value < 0 => format!("[rb-{}]", -(value as i128)),
+        2 => format!("[rb+{}]", value),
+        _ => return None,
+    })
+}
+
+pub fn disassemble(memory: &[i64], address: usize) -> (String, usize) {
+    let data = || (format!("data {}", memory[address]), 1);
+
+    let instruction = memory[address];
+    let opcode = instruction % 100;
+
+    let name = match mnemonic(opcode) {
+        Some(name) => name,
+        None => return data(),
+    };
+
+    let count = parameter_count(opcode);
+
+    if address + count >= memory.len() {
+        return data();
+    }
+
+    let mut parameters = Vec::new();
+    let mut place = 100;
+
+    for i in 1..=count {
+        match format_parameter(memory[address + i], instruction / place % 10) {
+            Some(parameter) => parameters.push(parameter),
+            None => return data(),
+        }
+        place *= 10;
+    }
+
+    if parameters.is_empty() {
+        (name.to_string(), 1)
+    } else {
+        (format!("{} {}", name, parameters.join(", ")), count + 1)
+    }
+}
+
+pub fn disassemble_program(program: &[i64]) -> Vec<(usize, String)> {
+    let mut lines = Vec::new();
+    let mut address = 0;
+
+    while address < program.len() {
+        let (text, len) = disassemble(program, address);
+        lines.push((address, text));
+        address += len;
+    }
+
+    lines
+}
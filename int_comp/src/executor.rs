@@ -0,0 +1,126 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+struct ThreadWaker {
+    thread: Thread,
+    woken: AtomicBool,
+}
+
+impl ThreadWaker {
+    fn new() -> Arc<ThreadWaker> {
+        Arc::new(ThreadWaker {
+            thread: thread::current(),
+            woken: AtomicBool::new(true),
+        })
+    }
+
+    fn take(&self) -> bool {
+        self.woken.swap(false, Ordering::SeqCst)
+    }
+}
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::SeqCst);
+        self.thread.unpark();
+    }
+}
+
+pub struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if self.yielded {
+            Poll::Ready(())
+        } else {
+            self.yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+pub fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let thread_waker = ThreadWaker::new();
+    let waker = Waker::from(thread_waker.clone());
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+
+    loop {
+        if thread_waker.take() {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        } else {
+            thread::park();
+        }
+    }
+}
+
+struct Task<'a> {
+    future: Pin<Box<dyn Future<Output = ()> + 'a>>,
+    waker: Arc<ThreadWaker>,
+}
+
+#[derive(Default)]
+pub struct Executor<'a> {
+    tasks: Vec<Pin<Box<dyn Future<Output = ()> + 'a>>>,
+}
+
+impl<'a> Executor<'a> {
+    pub fn new() -> Executor<'a> {
+        Executor { tasks: Vec::new() }
+    }
+
+    pub fn spawn<F: Future<Output = ()> + 'a>(&mut self, future: F) {
+        self.tasks.push(Box::pin(future));
+    }
+
+    pub fn run(&mut self) {
+        let mut tasks = self
+            .tasks
+            .drain(..)
+            .map(|future| Task {
+                future,
+                waker: ThreadWaker::new(),
+            })
+            .collect::<Vec<_>>();
+
+        while !tasks.is_empty() {
+            let mut polled = false;
+
+            tasks.retain_mut(|task| {
+                if !task.waker.take() {
+                    return true;
+                }
+
+                polled = true;
+
+                let waker = Waker::from(task.waker.clone());
+                let mut cx = Context::from_waker(&waker);
+
+                task.future.as_mut().poll(&mut cx).is_pending()
+            });
+
+            if !polled {
+                thread::park();
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use super::{IntcodeComputer, IntcodeOutput};
+use std::panic::{self, AssertUnwindSafe};
+
+pub const MEMORY_SIZE: usize = 4096;
+pub const FUEL: usize = 10_000;
+
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + self.below((high - low) as u64) as i64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Halt,
+    NeedMoreInput,
+    OutOfFuel,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Execution {
+    pub outcome: Outcome,
+    pub output: Vec<i64>,
+}
+
+fn generate_operand(rng: &mut Rng, len: usize) -> i64 {
+    match rng.below(40) {
+        0 => -rng.range(1, 16),
+        1 => MEMORY_SIZE as i64 + rng.range(-4, 4),
+        2 => i64::MAX - rng.range(0, 4),
+        3 => i64::MIN + rng.range(0, 4),
+        _ => rng.range(0, len as i64 + 8),
+    }
+}
+
+pub fn generate_program(rng: &mut Rng, len: usize) -> Vec<i64> {
+    let mut program = Vec::with_capacity(len + 3);
+
+    while program.len() < len {
+        if rng.below(40) == 0 {
+            program.push(rng.range(-1000, 1000));
+            continue;
+        }
+
+        let (opcode, parameters) = match rng.below(10) {
+            0 => (1, 3),
+            1 => (2, 3),
+            2 => (3, 1),
+            3 => (4, 1),
+            4 => (5, 2),
+            5 => (6, 2),
+            6 => (7, 3),
+            7 => (8, 3),
+            8 => (9, 1),
+            _ => (99, 0),
+        };
+
+        let mut instruction = opcode;
+        let mut place = 100;
+
+        for _ in 0..parameters {
+            let mode = if rng.below(100) == 0 { 3 } else { rng.below(3) };
+            instruction += mode as i64 * place;
+            place *= 10;
+        }
+
+        program.push(instruction);
+
+        for _ in 0..parameters {
+            program.push(generate_operand(rng, len));
+        }
+    }
+
+    program.truncate(len);
+    program
+}
+
+pub fn program_from_bytes(data: &[u8]) -> Vec<i64> {
+    data.chunks(2)
+        .map(|chunk| match chunk {
+            [low, high] => i16::from_le_bytes([*low, *high]) as i64,
+            [low] => *low as i64,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+fn run(program: &[i64], input: &[i64], outputs: usize) -> Option<Vec<i64>> {
+    let mut intcode = IntcodeComputer::with_memory_size(program, MEMORY_SIZE);
+
+    match intcode.run_with_fuel(input, Some(outputs as i32), FUEL as u64) {
+        Ok(IntcodeOutput::Interrupt(output)) => Some(output),
+        _ => None,
+    }
+}
+
+fn collect_output(program: &[i64], input: &[i64]) -> Vec<i64> {
+    let mut low = 0;
+    let mut high = 1;
+
+    while run(program, input, high).is_some() {
+        low = high;
+        high *= 2;
+    }
+
+    while high - low > 1 {
+        let middle = (low + high) / 2;
+
+        if run(program, input, middle).is_some() {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    if low == 0 {
+        Vec::new()
+    } else {
+        run(program, input, low).unwrap_or_default()
+    }
+}
+
+pub fn execute(program: &[i64], input: &[i64]) -> Execution {
+    let mut intcode = IntcodeComputer::with_memory_size(program, MEMORY_SIZE);
+
+    match intcode.run_steps(input, FUEL as u64) {
+        Ok(Some(IntcodeOutput::Halt(output))) => Execution {
+            outcome: Outcome::Halt,
+            output,
+        },
+        Ok(Some(IntcodeOutput::NeedMoreInput)) => Execution {
+            outcome: Outcome::NeedMoreInput,
+            output: collect_output(program, input),
+        },
+        Ok(None) => Execution {
+            outcome: Outcome::OutOfFuel,
+            output: collect_output(program, input),
+        },
+        Ok(Some(IntcodeOutput::Interrupt(_))) | Err(_) => Execution {
+            outcome: Outcome::Error,
+            output: collect_output(program, input),
+        },
+    }
+}
+
+pub fn check(program: &[i64], input: &[i64]) -> Result<Execution, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| execute(program, input)))
+        .map_err(|_| format!("Panic while running {:?}", program))
+}
+
+pub fn differential(program: &[i64], input: &[i64]) -> Result<(), String> {
+    let execution = check(program, input)?;
+
+    let reference = panic::catch_unwind(AssertUnwindSafe(|| {
+        super::reference::execute(program, input, MEMORY_SIZE, FUEL)
+    }))
+    .map_err(|_| format!("Reference panic while running {:?}", program))?;
+
+    if execution != reference {
+        return Err(format!(
+            "Mismatch while running {:?}: {:?} != {:?}",
+            program, execution, reference
+        ));
+    }
+
+    Ok(())
+}
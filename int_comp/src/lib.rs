@@ -1,5 +1,24 @@
 use std::convert::TryFrom;
 use std::error::Error;
+use std::future::Future;
+
+pub mod coverage;
+pub mod disasm;
+pub mod executor;
+pub mod fuzz;
+pub mod memory;
+pub mod profile;
+pub mod reference;
+pub mod session;
+pub mod symbolic;
+
+pub fn parse_program(input: &str) -> Result<Vec<i64>, Box<dyn Error>> {
+    Ok(input
+        .split(',')
+        .filter(|v| !v.trim().is_empty())
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?)
+}
 
 #[derive(Debug)]
 enum ParameterMode {
@@ -77,23 +96,45 @@ impl TryFrom<i64> for Opcode {
     }
 }
 
-fn get_parameter(memory: &[i64], value: i64, mode: ParameterMode, relative_base: i64) -> i64 {
+fn check_address(memory: &[i64], address: i64) -> Result<usize, Box<dyn Error>> {
+    if address < 0 || address as usize >= memory.len() {
+        return Err(format!("Address out of range: {}", address).into());
+    }
+
+    Ok(address as usize)
+}
+
+fn relative_address(relative_base: i64, value: i64) -> Result<i64, Box<dyn Error>> {
+    relative_base
+        .checked_add(value)
+        .ok_or_else(|| "Relative address overflow".into())
+}
+
+fn get_parameter(
+    memory: &[i64],
+    value: i64,
+    mode: ParameterMode,
+    relative_base: i64,
+) -> Result<i64, Box<dyn Error>> {
     match mode {
-        ParameterMode::Position => memory[value as usize],
-        ParameterMode::Immediate => value,
-        ParameterMode::Relative => memory[(relative_base + value) as usize],
+        ParameterMode::Position => Ok(memory[check_address(memory, value)?]),
+        ParameterMode::Immediate => Ok(value),
+        ParameterMode::Relative => {
+            Ok(memory[check_address(memory, relative_address(relative_base, value)?)?])
+        }
     }
 }
 
 fn get_address(
+    memory: &[i64],
     value: i64,
     mode: ParameterMode,
     relative_base: i64,
 ) -> Result<usize, Box<dyn Error>> {
     match mode {
-        ParameterMode::Position => Ok(value as usize),
+        ParameterMode::Position => check_address(memory, value),
         ParameterMode::Immediate => Err("Invalid address mode for write".into()),
-        ParameterMode::Relative => Ok((relative_base + value) as usize),
+        ParameterMode::Relative => check_address(memory, relative_address(relative_base, value)?),
     }
 }
 
@@ -114,6 +155,24 @@ impl IntcodeOutput {
     }
 }
 
+pub trait Trace {
+    fn instruction(&mut self, _pc: usize, _instruction: i64) {}
+    fn relative_base_offset(&mut self, _pc: usize, _offset: i64) {}
+    fn branch(&mut self, _pc: usize, _taken: bool) {}
+    fn input(&mut self, _value: i64) {}
+    fn output(&mut self, _value: i64) {}
+}
+
+impl Trace for () {}
+
+enum Step {
+    Continue,
+    NeedInput,
+    Output(i64),
+    Halt,
+}
+
+#[derive(Clone)]
 pub struct IntcodeComputer {
     pc: usize,
     relative_base: i64,
@@ -123,9 +182,13 @@ pub struct IntcodeComputer {
 
 impl IntcodeComputer {
     pub fn new(program: &[i64]) -> IntcodeComputer {
+        IntcodeComputer::with_memory_size(program, 1024 * 1024)
+    }
+
+    pub fn with_memory_size(program: &[i64], memory_size: usize) -> IntcodeComputer {
         let memory = {
             let mut memory = Vec::new();
-            memory.resize_with(1 * 1024 * 1024, Default::default);
+            memory.resize_with(memory_size.max(program.len()), Default::default);
             memory[..program.len()].copy_from_slice(program);
             memory.into_boxed_slice()
         };
@@ -133,7 +196,7 @@ impl IntcodeComputer {
         IntcodeComputer {
             pc: 0,
             relative_base: 0,
-            memory: memory,
+            memory,
             output: Vec::new(),
         }
     }
@@ -145,129 +208,247 @@ impl IntcodeComputer {
         input: &[i64],
         outputs_before_interrupt: Option<i32>,
     ) -> Result<IntcodeOutput, Box<dyn Error>> {
-        let mut input_counter = 0;
-
-        loop {
-            let opcode = Opcode::try_from(self.memory[self.pc])?;
-
-            match opcode {
-                Opcode::Add(a_mode, b_mode, res_mode) => {
-                    let a = self.memory[self.pc + 1];
-                    let b = self.memory[self.pc + 2];
-                    let res = self.memory[self.pc + 3];
-
-                    self.memory[get_address(res, res_mode, self.relative_base)?] =
-                        get_parameter(&self.memory, a, a_mode, self.relative_base)
-                            + get_parameter(&self.memory, b, b_mode, self.relative_base);
-
-                    self.pc += 4;
-                }
-                Opcode::Mult(a_mode, b_mode, res_mode) => {
-                    let a = self.memory[self.pc + 1];
-                    let b = self.memory[self.pc + 2];
-                    let res = self.memory[self.pc + 3];
+        self.run_traced(input, outputs_before_interrupt, &mut ())
+    }
 
-                    self.memory[get_address(res, res_mode, self.relative_base)?] =
-                        get_parameter(&self.memory, a, a_mode, self.relative_base)
-                            * get_parameter(&self.memory, b, b_mode, self.relative_base);
+    pub fn run_traced<T: Trace>(
+        &mut self,
+        input: &[i64],
+        outputs_before_interrupt: Option<i32>,
+        trace: &mut T,
+    ) -> Result<IntcodeOutput, Box<dyn Error>> {
+        self.run_limited(input, outputs_before_interrupt, trace, None)
+            .map(|output| output.unwrap())
+    }
 
-                    self.pc += 4;
-                }
-                Opcode::Input(mode) => {
-                    let value = self.memory[self.pc + 1];
+    pub fn run_with_fuel(
+        &mut self,
+        input: &[i64],
+        outputs_before_interrupt: Option<i32>,
+        fuel: u64,
+    ) -> Result<IntcodeOutput, Box<dyn Error>> {
+        self.run_limited(input, outputs_before_interrupt, &mut (), Some(fuel))?
+            .ok_or_else(|| "Out of fuel".into())
+    }
 
-                    if input_counter >= input.len() {
-                        return Ok(IntcodeOutput::NeedMoreInput);
-                    }
+    pub fn run_steps(
+        &mut self,
+        input: &[i64],
+        steps: u64,
+    ) -> Result<Option<IntcodeOutput>, Box<dyn Error>> {
+        self.run_limited(input, None, &mut (), Some(steps))
+    }
 
-                    self.memory[get_address(value, mode, self.relative_base)?] =
-                        input[input_counter];
+    fn run_limited<T: Trace>(
+        &mut self,
+        input: &[i64],
+        outputs_before_interrupt: Option<i32>,
+        trace: &mut T,
+        mut fuel: Option<u64>,
+    ) -> Result<Option<IntcodeOutput>, Box<dyn Error>> {
+        let mut input_counter = 0;
 
-                    input_counter += 1;
-                    self.pc += 2;
+        loop {
+            if let Some(fuel) = &mut fuel {
+                match fuel.checked_sub(1) {
+                    Some(remaining) => *fuel = remaining,
+                    None => return Ok(None),
                 }
-                Opcode::Output(mode) => {
-                    let value = self.memory[self.pc + 1];
+            }
 
-                    self.output
-                        .push(get_parameter(&self.memory, value, mode, self.relative_base));
+            let mut next_input = input.get(input_counter).copied();
 
-                    self.pc += 2;
+            match self.step(&mut next_input, trace)? {
+                Step::Continue => {}
+                Step::NeedInput => return Ok(Some(IntcodeOutput::NeedMoreInput)),
+                Step::Output(value) => {
+                    self.output.push(value);
 
                     if let Some(outputs_before_interrupt) = outputs_before_interrupt {
                         if self.output.len() == outputs_before_interrupt as usize {
-                            return Ok(IntcodeOutput::Interrupt(
+                            return Ok(Some(IntcodeOutput::Interrupt(
                                 self.output.drain(..).collect::<Vec<_>>(),
-                            ));
+                            )));
                         }
                     }
                 }
-                Opcode::JumpIfTrue(a_mode, b_mode) => {
-                    let a = self.memory[self.pc + 1];
-                    let b = self.memory[self.pc + 2];
-
-                    if get_parameter(&self.memory, a, a_mode, self.relative_base) != 0 {
-                        self.pc =
-                            get_parameter(&self.memory, b, b_mode, self.relative_base) as usize;
-                    } else {
-                        self.pc += 3;
-                    }
-                }
-                Opcode::JumpIfFalse(a_mode, b_mode) => {
-                    let a = self.memory[self.pc + 1];
-                    let b = self.memory[self.pc + 2];
-
-                    if get_parameter(&self.memory, a, a_mode, self.relative_base) == 0 {
-                        self.pc =
-                            get_parameter(&self.memory, b, b_mode, self.relative_base) as usize;
-                    } else {
-                        self.pc += 3;
-                    }
+                Step::Halt => {
+                    return Ok(Some(IntcodeOutput::Halt(
+                        self.output.drain(..).collect::<Vec<_>>(),
+                    )));
                 }
-                Opcode::LessThen(a_mode, b_mode, res_mode) => {
-                    let a = self.memory[self.pc + 1];
-                    let b = self.memory[self.pc + 2];
-                    let res = self.memory[self.pc + 3];
-
-                    if get_parameter(&self.memory, a, a_mode, self.relative_base)
-                        < get_parameter(&self.memory, b, b_mode, self.relative_base)
-                    {
-                        self.memory[get_address(res, res_mode, self.relative_base)?] = 1;
-                    } else {
-                        self.memory[get_address(res, res_mode, self.relative_base)?] = 0;
-                    }
+            }
 
-                    self.pc += 4;
-                }
-                Opcode::Equals(a_mode, b_mode, res_mode) => {
-                    let a = self.memory[self.pc + 1];
-                    let b = self.memory[self.pc + 2];
-                    let res = self.memory[self.pc + 3];
-
-                    if get_parameter(&self.memory, a, a_mode, self.relative_base)
-                        == get_parameter(&self.memory, b, b_mode, self.relative_base)
-                    {
-                        self.memory[get_address(res, res_mode, self.relative_base)?] = 1;
-                    } else {
-                        self.memory[get_address(res, res_mode, self.relative_base)?] = 0;
-                    }
+            if next_input.is_none() && input_counter < input.len() {
+                input_counter += 1;
+            }
+        }
+    }
+
+    pub async fn run_async<I, F, O>(
+        &mut self,
+        mut input: I,
+        mut output: O,
+    ) -> Result<(), Box<dyn Error>>
+    where
+        I: FnMut() -> F,
+        F: Future<Output = Option<i64>>,
+        O: FnMut(i64),
+    {
+        let mut next_input = None;
 
-                    self.pc += 4;
+        loop {
+            match self.step(&mut next_input, &mut ())? {
+                Step::Continue => {}
+                Step::NeedInput => match input().await {
+                    Some(value) => next_input = Some(value),
+                    None => return Ok(()),
+                },
+                Step::Output(value) => {
+                    output(value);
+                    executor::yield_now().await;
                 }
-                Opcode::RelativeBaseOffset(mode) => {
-                    let value = self.memory[self.pc + 1];
+                Step::Halt => return Ok(()),
+            }
+        }
+    }
+
+    fn operand(&self, offset: usize) -> Result<i64, Box<dyn Error>> {
+        self.memory
+            .get(self.pc + offset)
+            .copied()
+            .ok_or_else(|| format!("Operand out of range at pc {}", self.pc).into())
+    }
+
+    fn parameter(&self, offset: usize, mode: ParameterMode) -> Result<i64, Box<dyn Error>> {
+        get_parameter(
+            &self.memory,
+            self.operand(offset)?,
+            mode,
+            self.relative_base,
+        )
+    }
+
+    fn write(
+        &mut self,
+        offset: usize,
+        mode: ParameterMode,
+        value: i64,
+    ) -> Result<(), Box<dyn Error>> {
+        let address = get_address(
+            &self.memory,
+            self.operand(offset)?,
+            mode,
+            self.relative_base,
+        )?;
+        self.memory[address] = value;
+        Ok(())
+    }
+
+    fn jump(&mut self, target: i64) -> Result<(), Box<dyn Error>> {
+        self.pc = check_address(&self.memory, target)?;
+        Ok(())
+    }
+
+    fn step<T: Trace>(
+        &mut self,
+        input: &mut Option<i64>,
+        trace: &mut T,
+    ) -> Result<Step, Box<dyn Error>> {
+        let instruction = self.operand(0)?;
+        let opcode = Opcode::try_from(instruction)?;
+
+        if let (Opcode::Input(_), None) = (&opcode, &input) {
+            return Ok(Step::NeedInput);
+        }
 
-                    self.relative_base +=
-                        get_parameter(&self.memory, value, mode, self.relative_base);
+        trace.instruction(self.pc, instruction);
 
-                    self.pc += 2;
+        match opcode {
+            Opcode::Add(a_mode, b_mode, res_mode) => {
+                let a = self.parameter(1, a_mode)?;
+                let b = self.parameter(2, b_mode)?;
+
+                let res = a.checked_add(b).ok_or("Arithmetic overflow")?;
+                self.write(3, res_mode, res)?;
+
+                self.pc += 4;
+            }
+            Opcode::Mult(a_mode, b_mode, res_mode) => {
+                let a = self.parameter(1, a_mode)?;
+                let b = self.parameter(2, b_mode)?;
+
+                let res = a.checked_mul(b).ok_or("Arithmetic overflow")?;
+                self.write(3, res_mode, res)?;
+
+                self.pc += 4;
+            }
+            Opcode::Input(mode) => {
+                let input = input.take().ok_or("Missing input")?;
+
+                self.write(1, mode, input)?;
+                trace.input(input);
+
+                self.pc += 2;
+            }
+            Opcode::Output(mode) => {
+                let value = self.parameter(1, mode)?;
+                trace.output(value);
+
+                self.pc += 2;
+
+                return Ok(Step::Output(value));
+            }
+            Opcode::JumpIfTrue(a_mode, b_mode) => {
+                if self.parameter(1, a_mode)? != 0 {
+                    let target = self.parameter(2, b_mode)?;
+                    trace.branch(self.pc, true);
+                    self.jump(target)?;
+                } else {
+                    trace.branch(self.pc, false);
+                    self.pc += 3;
                 }
-                Opcode::Halt => {
-                    return Ok(IntcodeOutput::Halt(
-                        self.output.drain(..).collect::<Vec<_>>(),
-                    ));
+            }
+            Opcode::JumpIfFalse(a_mode, b_mode) => {
+                if self.parameter(1, a_mode)? == 0 {
+                    let target = self.parameter(2, b_mode)?;
+                    trace.branch(self.pc, true);
+                    self.jump(target)?;
+                } else {
+                    trace.branch(self.pc, false);
+                    self.pc += 3;
                 }
             }
+            Opcode::LessThen(a_mode, b_mode, res_mode) => {
+                let a = self.parameter(1, a_mode)?;
+                let b = self.parameter(2, b_mode)?;
+
+                self.write(3, res_mode, if a < b { 1 } else { 0 })?;
+
+                self.pc += 4;
+            }
+            Opcode::Equals(a_mode, b_mode, res_mode) => {
+                let a = self.parameter(1, a_mode)?;
+                let b = self.parameter(2, b_mode)?;
+
+                self.write(3, res_mode, if a == b { 1 } else { 0 })?;
+
+                self.pc += 4;
+            }
+            Opcode::RelativeBaseOffset(mode) => {
+                let value = self.parameter(1, mode)?;
+
+                self.relative_base = relative_address(self.relative_base, value)?;
+                trace.relative_base_offset(self.pc, value);
+
+                self.pc += 2;
+            }
+            Opcode::Halt => return Ok(Step::Halt),
         }
+
+        Ok(Step::Continue)
     }
 }
+
+#[cfg(test)]
+mod tests;
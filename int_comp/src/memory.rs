@@ -0,0 +1,228 @@
+use super::disasm;
+use super::IntcodeComputer;
+use std::error::Error;
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+const CONTEXT: usize = 4;
+const MERGE_GAP: usize = 4;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dump {
+    pub pc: usize,
+    pub relative_base: i64,
+    pub memory: Vec<i64>,
+}
+
+impl fmt::Display for Dump {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# pc {}", self.pc)?;
+        writeln!(f, "# relative_base {}", self.relative_base)?;
+
+        let memory = self
+            .memory
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+
+        writeln!(f, "{}", memory.join(","))
+    }
+}
+
+impl FromStr for Dump {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut dump = Dump::default();
+        let mut program = String::new();
+
+        for line in input.lines().map(|line| line.trim()) {
+            if let Some(comment) = line.strip_prefix('#') {
+                let parts = comment.split_whitespace().collect::<Vec<_>>();
+
+                match parts.as_slice() {
+                    ["pc", pc] => dump.pc = pc.parse()?,
+                    ["relative_base", relative_base] => {
+                        dump.relative_base = relative_base.parse()?
+                    }
+                    _ => {}
+                }
+            } else {
+                program.push_str(line);
+            }
+        }
+
+        dump.memory = program
+            .split(',')
+            .filter(|v| !v.trim().is_empty())
+            .map(|v| v.trim().parse::<i64>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(dump)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Patch {
+    pub pokes: Vec<(usize, i64)>,
+}
+
+impl FromStr for Patch {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut pokes = Vec::new();
+
+        for line in input.lines().map(|line| line.trim()) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.splitn(2, ':');
+            let address = parts.next().unwrap_or("").trim().parse::<usize>()?;
+            let values = parts
+                .next()
+                .ok_or_else(|| format!("Invalid patch line: {}", line))?;
+
+            for (offset, value) in values.split(',').enumerate() {
+                pokes.push((address + offset, value.trim().parse::<i64>()?));
+            }
+        }
+
+        Ok(Patch { pokes })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub start: usize,
+    pub before: Vec<i64>,
+    pub after: Vec<i64>,
+}
+
+impl IntcodeComputer {
+    pub fn dump(&self) -> Dump {
+        let len = self
+            .memory
+            .iter()
+            .rposition(|value| *value != 0)
+            .map_or(0, |last| last + 1);
+
+        Dump {
+            pc: self.pc,
+            relative_base: self.relative_base,
+            memory: self.memory[..len].to_vec(),
+        }
+    }
+
+    pub fn from_dump(dump: &Dump) -> IntcodeComputer {
+        let mut intcode = IntcodeComputer::new(&dump.memory);
+        intcode.pc = dump.pc;
+        intcode.relative_base = dump.relative_base;
+        intcode
+    }
+
+    pub fn poke(&mut self, address: usize, value: i64) -> Result<(), Box<dyn Error>> {
+        let cell = self
+            .memory
+            .get_mut(address)
+            .ok_or_else(|| format!("Address out of range: {}", address))?;
+
+        *cell = value;
+
+        Ok(())
+    }
+
+    pub fn apply_patch(&mut self, patch: &Patch) -> Result<(), Box<dyn Error>> {
+        for (address, value) in &patch.pokes {
+            self.poke(*address, *value)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn diff(before: &Dump, after: &Dump) -> Vec<Region> {
+    let get = |memory: &[i64], address: usize| memory.get(address).copied().unwrap_or(0);
+
+    let changed = (0..before.memory.len().max(after.memory.len()))
+        .filter(|address| get(&before.memory, *address) != get(&after.memory, *address))
+        .collect::<Vec<_>>();
+
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+
+    for address in changed {
+        match ranges.last_mut() {
+            Some((_, end)) if address <= *end + MERGE_GAP => *end = address + 1,
+            _ => ranges.push((address, address + 1)),
+        }
+    }
+
+    ranges
+        .into_iter()
+        .map(|(start, end)| Region {
+            start,
+            before: (start..end).map(|a| get(&before.memory, a)).collect(),
+            after: (start..end).map(|a| get(&after.memory, a)).collect(),
+        })
+        .collect()
+}
+
+fn context(memory: &[i64], start: usize, end: usize) -> Vec<(usize, String)> {
+    let mut lines = Vec::new();
+    let mut address = 0;
+
+    while address < end.min(memory.len()) {
+        let (text, len) = disasm::disassemble(memory, address);
+
+        if address + len > start {
+            lines.push((address, text));
+        }
+
+        address += len;
+    }
+
+    lines
+}
+
+pub fn format_diff(before: &Dump, after: &Dump) -> String {
+    let mut output = String::new();
+
+    if before.pc != after.pc {
+        writeln!(output, "pc: {} -> {}", before.pc, after.pc).unwrap();
+    }
+
+    if before.relative_base != after.relative_base {
+        writeln!(
+            output,
+            "relative_base: {} -> {}",
+            before.relative_base, after.relative_base
+        )
+        .unwrap();
+    }
+
+    for region in diff(before, after) {
+        let end = region.start + region.before.len();
+
+        writeln!(output, "@@ {}..{} @@", region.start, end).unwrap();
+
+        for (offset, (a, b)) in region.before.iter().zip(&region.after).enumerate() {
+            if a != b {
+                writeln!(output, "  {:>6}: {} -> {}", region.start + offset, a, b).unwrap();
+            }
+        }
+
+        let window_start = region.start.saturating_sub(CONTEXT);
+        let window_end = end + CONTEXT;
+
+        for (address, text) in context(&before.memory, window_start, window_end) {
+            writeln!(output, "- {:>6}  {}", address, text).unwrap();
+        }
+
+        for (address, text) in context(&after.memory, window_start, window_end) {
+            writeln!(output, "+ {:>6}  {}", address, text).unwrap();
+        }
+    }
+
+    output
+}
@@ -0,0 +1,153 @@
+use super::disasm;
+use super::Trace;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+const MAX_DEPTH: usize = 64;
+
+fn frame_name(frame: Option<&usize>) -> String {
+    match frame {
+        Some(pc) => format!("fn_{}", pc),
+        None => "main".to_string(),
+    }
+}
+
+#[derive(Default)]
+pub struct Profile {
+    pub instructions: u64,
+    pub pc_counts: HashMap<usize, u64>,
+    pub opcode_counts: HashMap<i64, u64>,
+    pub stack_counts: HashMap<Vec<usize>, u64>,
+    stack: Vec<usize>,
+    overflow: usize,
+}
+
+impl Trace for Profile {
+    fn instruction(&mut self, pc: usize, instruction: i64) {
+        self.instructions += 1;
+        *self.pc_counts.entry(pc).or_insert(0) += 1;
+        *self.opcode_counts.entry(instruction % 100).or_insert(0) += 1;
+
+        if let Some(count) = self.stack_counts.get_mut(self.stack.as_slice()) {
+            *count += 1;
+        } else {
+            self.stack_counts.insert(self.stack.clone(), 1);
+        }
+    }
+
+    fn relative_base_offset(&mut self, pc: usize, offset: i64) {
+        if offset > 0 {
+            if self.stack.len() < MAX_DEPTH {
+                self.stack.push(pc);
+            } else {
+                self.overflow += 1;
+            }
+        } else if offset < 0 {
+            if self.overflow > 0 {
+                self.overflow -= 1;
+            } else {
+                self.stack.pop();
+            }
+        }
+    }
+}
+
+impl Profile {
+    pub fn new() -> Profile {
+        Profile::default()
+    }
+
+    pub fn frame_counts(&self) -> Vec<(Option<usize>, u64, u64)> {
+        let mut frames = HashMap::<Option<usize>, (u64, u64)>::new();
+
+        for (stack, count) in &self.stack_counts {
+            let mut seen = Vec::new();
+
+            for frame in stack.iter().map(Some).chain(Some(None)) {
+                let frame = frame.copied();
+                if !seen.contains(&frame) {
+                    frames.entry(frame).or_insert((0, 0)).0 += count;
+                    seen.push(frame);
+                }
+            }
+
+            frames.entry(stack.last().copied()).or_insert((0, 0)).1 += count;
+        }
+
+        let mut frames = frames
+            .into_iter()
+            .map(|(frame, (inclusive, exclusive))| (frame, inclusive, exclusive))
+            .collect::<Vec<_>>();
+        frames.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        frames
+    }
+
+    pub fn report(&self, top: usize) -> String {
+        let mut report = String::new();
+        let percent = |count: u64| 100.0 * count as f64 / self.instructions.max(1) as f64;
+
+        writeln!(report, "Instructions: {}", self.instructions).unwrap();
+
+        writeln!(report, "\nOpcodes:").unwrap();
+        let mut opcodes = self.opcode_counts.iter().collect::<Vec<_>>();
+        opcodes.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        for (opcode, count) in opcodes {
+            writeln!(
+                report,
+                "  {:<6} {:>12} {:>6.2}%",
+                disasm::mnemonic(*opcode).unwrap_or("???"),
+                count,
+                percent(*count)
+            )
+            .unwrap();
+        }
+
+        writeln!(report, "\nHot spots:").unwrap();
+        let mut pcs = self.pc_counts.iter().collect::<Vec<_>>();
+        pcs.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        for (pc, count) in pcs.into_iter().take(top) {
+            writeln!(
+                report,
+                "  {:>6} {:>12} {:>6.2}%",
+                pc,
+                count,
+                percent(*count)
+            )
+            .unwrap();
+        }
+
+        writeln!(report, "\nFrames (inclusive, self):").unwrap();
+        for (frame, inclusive, exclusive) in self.frame_counts().into_iter().take(top) {
+            writeln!(
+                report,
+                "  {:<10} {:>12} {:>6.2}% {:>12} {:>6.2}%",
+                frame_name(frame.as_ref()),
+                inclusive,
+                percent(inclusive),
+                exclusive,
+                percent(exclusive)
+            )
+            .unwrap();
+        }
+
+        report
+    }
+
+    pub fn folded_stacks(&self) -> String {
+        let mut stacks = self
+            .stack_counts
+            .iter()
+            .map(|(stack, count)| {
+                let names = Some(frame_name(None))
+                    .into_iter()
+                    .chain(stack.iter().map(|pc| frame_name(Some(pc))))
+                    .collect::<Vec<_>>();
+
+                format!("{} {}", names.join(";"), count)
+            })
+            .collect::<Vec<_>>();
+
+        stacks.sort();
+        stacks.join("\n") + "\n"
+    }
+}
@@ -0,0 +1,173 @@
+use super::fuzz::{Execution, Outcome};
+
+struct Machine {
+    memory: Vec<i64>,
+    pc: i64,
+    relative_base: i64,
+}
+
+impl Machine {
+    fn read(&self, address: i64) -> Option<i64> {
+        if address < 0 || address >= self.memory.len() as i64 {
+            return None;
+        }
+
+        Some(self.memory[address as usize])
+    }
+
+    fn mode(&self, parameter: u32) -> Option<i64> {
+        let mode = self.read(self.pc)? / 10i64.pow(parameter + 1) % 10;
+
+        if mode > 2 {
+            return None;
+        }
+
+        Some(mode)
+    }
+
+    fn address(&self, parameter: u32) -> Option<i64> {
+        let value = self.read(self.pc + parameter as i64)?;
+
+        let address = match self.mode(parameter)? {
+            0 => value,
+            2 => self.relative_base.checked_add(value)?,
+            _ => return None,
+        };
+
+        self.read(address)?;
+
+        Some(address)
+    }
+
+    fn value(&self, parameter: u32) -> Option<i64> {
+        let value = self.read(self.pc + parameter as i64)?;
+
+        match self.mode(parameter)? {
+            0 => self.read(value),
+            1 => Some(value),
+            _ => self.read(self.relative_base.checked_add(value)?),
+        }
+    }
+
+    fn write(&mut self, parameter: u32, value: i64) -> Option<()> {
+        let address = self.address(parameter)?;
+        self.memory[address as usize] = value;
+        Some(())
+    }
+
+    fn jump(&mut self, target: i64) -> Option<()> {
+        self.read(target)?;
+        self.pc = target;
+        Some(())
+    }
+}
+
+pub fn execute(program: &[i64], input: &[i64], memory_size: usize, fuel: usize) -> Execution {
+    let mut memory = program.to_vec();
+    if memory.len() < memory_size {
+        memory.resize(memory_size, 0);
+    }
+
+    let mut machine = Machine {
+        memory,
+        pc: 0,
+        relative_base: 0,
+    };
+    let mut input = input.iter();
+    let mut output = Vec::new();
+
+    for _ in 0..fuel {
+        let result = step(&mut machine, &mut input, &mut output);
+
+        let outcome = match result {
+            Some(None) => continue,
+            Some(Some(outcome)) => outcome,
+            None => Outcome::Error,
+        };
+
+        return Execution { outcome, output };
+    }
+
+    Execution {
+        outcome: Outcome::OutOfFuel,
+        output,
+    }
+}
+
+fn step(
+    machine: &mut Machine,
+    input: &mut std::slice::Iter<i64>,
+    output: &mut Vec<i64>,
+) -> Option<Option<Outcome>> {
+    let instruction = machine.read(machine.pc)?;
+
+    let parameters = match instruction % 100 {
+        1 | 2 | 7 | 8 => 3,
+        5 | 6 => 2,
+        3 | 4 | 9 => 1,
+        99 => 0,
+        _ => return None,
+    };
+
+    for parameter in 1..=parameters {
+        machine.mode(parameter)?;
+    }
+
+    match instruction % 100 {
+        1 => {
+            let value = machine.value(1)?.checked_add(machine.value(2)?)?;
+            machine.write(3, value)?;
+            machine.pc += 4;
+        }
+        2 => {
+            let value = machine.value(1)?.checked_mul(machine.value(2)?)?;
+            machine.write(3, value)?;
+            machine.pc += 4;
+        }
+        3 => {
+            let value = match input.next() {
+                Some(value) => *value,
+                None => return Some(Some(Outcome::NeedMoreInput)),
+            };
+            machine.write(1, value)?;
+            machine.pc += 2;
+        }
+        4 => {
+            output.push(machine.value(1)?);
+            machine.pc += 2;
+        }
+        5 => {
+            if machine.value(1)? != 0 {
+                let target = machine.value(2)?;
+                machine.jump(target)?;
+            } else {
+                machine.pc += 3;
+            }
+        }
+        6 => {
+            if machine.value(1)? == 0 {
+                let target = machine.value(2)?;
+                machine.jump(target)?;
+            } else {
+                machine.pc += 3;
+            }
+        }
+        7 => {
+            let value = (machine.value(1)? < machine.value(2)?) as i64;
+            machine.write(3, value)?;
+            machine.pc += 4;
+        }
+        8 => {
+            let value = (machine.value(1)? == machine.value(2)?) as i64;
+            machine.write(3, value)?;
+            machine.pc += 4;
+        }
+        9 => {
+            machine.relative_base = machine.relative_base.checked_add(machine.value(1)?)?;
+            machine.pc += 2;
+        }
+        _ => return Some(Some(Outcome::Halt)),
+    }
+
+    Some(None)
+}
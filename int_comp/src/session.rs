@@ -0,0 +1,168 @@
+use super::{IntcodeComputer, Trace};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    Input { step: u64, value: i64 },
+    Output { step: u64, value: i64 },
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Event::Input { step, value } => write!(f, "in {} {}", step, value),
+            Event::Output { step, value } => write!(f, "out {} {}", step, value),
+        }
+    }
+}
+
+impl FromStr for Event {
+    type Err = Box<dyn Error>;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let parts = line.split_whitespace().collect::<Vec<_>>();
+
+        match parts.as_slice() {
+            ["in", step, value] => Ok(Event::Input {
+                step: step.parse()?,
+                value: value.parse()?,
+            }),
+            ["out", step, value] => Ok(Event::Output {
+                step: step.parse()?,
+                value: value.parse()?,
+            }),
+            _ => Err(format!("Invalid session line: {}", line).into()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Divergence {
+    pub index: usize,
+    pub expected: Option<Event>,
+    pub actual: Option<Event>,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let event = |event: &Option<Event>| match event {
+            Some(event) => event.to_string(),
+            None => "nothing".to_string(),
+        };
+
+        write!(
+            f,
+            "Divergence at event {}: expected {}, got {}",
+            self.index,
+            event(&self.expected),
+            event(&self.actual)
+        )
+    }
+}
+
+impl Error for Divergence {}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Session {
+    pub events: Vec<Event>,
+    steps: u64,
+}
+
+impl Trace for Session {
+    fn instruction(&mut self, _pc: usize, _instruction: i64) {
+        self.steps += 1;
+    }
+
+    fn input(&mut self, value: i64) {
+        self.events.push(Event::Input {
+            step: self.steps,
+            value,
+        });
+    }
+
+    fn output(&mut self, value: i64) {
+        self.events.push(Event::Output {
+            step: self.steps,
+            value,
+        });
+    }
+}
+
+impl fmt::Display for Session {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for event in &self.events {
+            writeln!(f, "{}", event)?;
+        }
+
+        writeln!(f, "steps {}", self.steps)
+    }
+}
+
+impl FromStr for Session {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut session = Session::new();
+
+        for line in input
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        {
+            match line.strip_prefix("steps ") {
+                Some(steps) => session.steps = steps.trim().parse()?,
+                None => session.events.push(line.parse()?),
+            }
+        }
+
+        Ok(session)
+    }
+}
+
+impl Session {
+    pub fn new() -> Session {
+        Session::default()
+    }
+
+    pub fn load(path: &str) -> Result<Session, Box<dyn Error>> {
+        fs::read_to_string(path)?.parse()
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    pub fn inputs(&self) -> Vec<i64> {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Input { value, .. } => Some(*value),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn replay(&self, program: &[i64]) -> Result<Option<Divergence>, Box<dyn Error>> {
+        let mut replayed = Session::new();
+
+        let output = IntcodeComputer::new(program).run_traced(&self.inputs(), None, &mut replayed);
+
+        let divergence = (0..self.events.len().max(replayed.events.len()))
+            .map(|index| Divergence {
+                index,
+                expected: self.events.get(index).copied(),
+                actual: replayed.events.get(index).copied(),
+            })
+            .find(|divergence| divergence.expected != divergence.actual);
+
+        match (divergence, output) {
+            (Some(divergence), _) => Ok(Some(divergence)),
+            (None, Err(err)) => Err(err),
+            (None, Ok(_)) => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,634 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::ops::RangeInclusive;
+
+const FUEL: usize = 1_000_000;
+const MEMORY_SIZE: usize = 1024 * 1024;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Linear {
+    pub terms: BTreeMap<usize, i64>,
+    pub constant: i64,
+}
+
+impl Linear {
+    pub fn constant(constant: i64) -> Linear {
+        Linear {
+            terms: BTreeMap::new(),
+            constant,
+        }
+    }
+
+    pub fn variable(variable: usize) -> Linear {
+        let mut terms = BTreeMap::new();
+        terms.insert(variable, 1);
+
+        Linear { terms, constant: 0 }
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    fn add(&self, other: &Linear) -> Option<Linear> {
+        let mut result = self.clone();
+        result.constant = result.constant.checked_add(other.constant)?;
+
+        for (variable, coefficient) in &other.terms {
+            let sum = result
+                .terms
+                .get(variable)
+                .unwrap_or(&0)
+                .checked_add(*coefficient)?;
+
+            if sum == 0 {
+                result.terms.remove(variable);
+            } else {
+                result.terms.insert(*variable, sum);
+            }
+        }
+
+        Some(result)
+    }
+
+    fn scale(&self, factor: i64) -> Option<Linear> {
+        if factor == 0 {
+            return Some(Linear::constant(0));
+        }
+
+        let mut terms = BTreeMap::new();
+        for (variable, coefficient) in &self.terms {
+            terms.insert(*variable, coefficient.checked_mul(factor)?);
+        }
+
+        Some(Linear {
+            terms,
+            constant: self.constant.checked_mul(factor)?,
+        })
+    }
+
+    fn sub(&self, other: &Linear) -> Option<Linear> {
+        self.add(&other.scale(-1)?)
+    }
+
+    pub fn evaluate(&self, assignment: &[i64]) -> Option<i64> {
+        let mut value = self.constant;
+
+        for (variable, coefficient) in &self.terms {
+            value = value.checked_add(coefficient.checked_mul(*assignment.get(*variable)?)?)?;
+        }
+
+        Some(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Relation {
+    Eq,
+    Ne,
+    Lt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Constraint {
+    pub expr: Linear,
+    pub relation: Relation,
+}
+
+impl Constraint {
+    pub fn equals(expr: &Linear, value: i64) -> Option<Constraint> {
+        Some(Constraint {
+            expr: expr.sub(&Linear::constant(value))?,
+            relation: Relation::Eq,
+        })
+    }
+
+    pub fn negate(&self) -> Constraint {
+        Constraint {
+            expr: self.expr.clone(),
+            relation: match self.relation {
+                Relation::Eq => Relation::Ne,
+                Relation::Ne => Relation::Eq,
+                Relation::Lt => Relation::Ge,
+                Relation::Ge => Relation::Lt,
+            },
+        }
+    }
+
+    pub fn holds(&self, assignment: &[i64]) -> bool {
+        match self.expr.evaluate(assignment) {
+            Some(value) => match self.relation {
+                Relation::Eq => value == 0,
+                Relation::Ne => value != 0,
+                Relation::Lt => value < 0,
+                Relation::Ge => value >= 0,
+            },
+            None => false,
+        }
+    }
+
+    fn bounds(&self, assignment: &[Option<i64>], domains: &[RangeInclusive<i64>]) -> (i128, i128) {
+        let mut low = self.expr.constant as i128;
+        let mut high = self.expr.constant as i128;
+
+        for (variable, coefficient) in &self.expr.terms {
+            let coefficient = *coefficient as i128;
+
+            match assignment[*variable] {
+                Some(value) => {
+                    low += coefficient * value as i128;
+                    high += coefficient * value as i128;
+                }
+                None => {
+                    let a = coefficient * *domains[*variable].start() as i128;
+                    let b = coefficient * *domains[*variable].end() as i128;
+                    low += a.min(b);
+                    high += a.max(b);
+                }
+            }
+        }
+
+        (low, high)
+    }
+
+    fn feasible(&self, assignment: &[Option<i64>], domains: &[RangeInclusive<i64>]) -> bool {
+        let (low, high) = self.bounds(assignment, domains);
+
+        match self.relation {
+            Relation::Eq => low <= 0 && 0 <= high,
+            Relation::Ne => !(low == 0 && high == 0),
+            Relation::Lt => low < 0,
+            Relation::Ge => high >= 0,
+        }
+    }
+
+    fn forced(&self, variable: usize, assignment: &[Option<i64>]) -> Option<Option<i64>> {
+        if self.relation != Relation::Eq {
+            return None;
+        }
+
+        let mut rest = self.expr.constant as i128;
+
+        for (other, coefficient) in &self.expr.terms {
+            if *other == variable {
+                continue;
+            }
+
+            rest += *coefficient as i128 * assignment[*other]? as i128;
+        }
+
+        let coefficient = *self.expr.terms.get(&variable)? as i128;
+
+        if rest % coefficient != 0 {
+            return Some(None);
+        }
+
+        Some(i64::try_from(-rest / coefficient).ok())
+    }
+}
+
+pub fn solve(constraints: &[Constraint], domains: &[RangeInclusive<i64>]) -> Option<Vec<i64>> {
+    fn search(
+        variable: usize,
+        assignment: &mut Vec<Option<i64>>,
+        constraints: &[Constraint],
+        domains: &[RangeInclusive<i64>],
+    ) -> bool {
+        if constraints
+            .iter()
+            .any(|constraint| !constraint.feasible(assignment, domains))
+        {
+            return false;
+        }
+
+        if variable == domains.len() {
+            return true;
+        }
+
+        let forced = constraints
+            .iter()
+            .filter_map(|constraint| constraint.forced(variable, assignment))
+            .next();
+
+        let candidates = match forced {
+            Some(Some(value)) if domains[variable].contains(&value) => value..=value,
+            Some(_) => return false,
+            None => domains[variable].clone(),
+        };
+
+        for value in candidates {
+            assignment[variable] = Some(value);
+
+            if search(variable + 1, assignment, constraints, domains) {
+                return true;
+            }
+        }
+
+        assignment[variable] = None;
+        false
+    }
+
+    let mut assignment = vec![None; domains.len()];
+
+    if search(0, &mut assignment, constraints, domains) {
+        Some(assignment.into_iter().map(|value| value.unwrap()).collect())
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    Input(usize),
+    Memory(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    pub source: Source,
+    pub domain: RangeInclusive<i64>,
+}
+
+#[derive(Debug, Clone)]
+enum Shadow {
+    Linear(Linear),
+    Compare(Constraint),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit {
+    Halt,
+    NeedMoreInput,
+    OutOfFuel,
+}
+
+pub struct Run {
+    pub exit: Exit,
+    pub path: Vec<Constraint>,
+    pub outputs: Vec<Linear>,
+    memory: Vec<i64>,
+    shadow: HashMap<usize, Shadow>,
+}
+
+impl Run {
+    pub fn memory(&self, address: usize) -> Linear {
+        match self.shadow.get(&address) {
+            Some(Shadow::Linear(linear)) => linear.clone(),
+            _ => Linear::constant(self.memory.get(address).copied().unwrap_or(0)),
+        }
+    }
+}
+
+struct Machine {
+    pc: usize,
+    relative_base: i64,
+    memory: Vec<i64>,
+    shadow: HashMap<usize, Shadow>,
+    path: Vec<Constraint>,
+}
+
+impl Machine {
+    fn check_address(address: i64) -> Result<usize, Box<dyn Error>> {
+        if address < 0 {
+            return Err(format!("Address out of range: {}", address).into());
+        }
+
+        Ok(address as usize)
+    }
+
+    fn read(&self, address: usize) -> i64 {
+        self.memory.get(address).copied().unwrap_or(0)
+    }
+
+    fn write(
+        &mut self,
+        address: usize,
+        value: i64,
+        shadow: Option<Shadow>,
+    ) -> Result<(), Box<dyn Error>> {
+        if address >= MEMORY_SIZE {
+            return Err(format!("Address out of range: {}", address).into());
+        }
+
+        if address >= self.memory.len() {
+            self.memory.resize(address + 1, 0);
+        }
+
+        self.memory[address] = value;
+
+        match shadow {
+            Some(shadow) => self.shadow.insert(address, shadow),
+            None => self.shadow.remove(&address),
+        };
+
+        Ok(())
+    }
+
+    fn constrain(&mut self, constraint: Constraint) {
+        if !self.path.contains(&constraint) {
+            self.path.push(constraint);
+        }
+    }
+
+    fn pin(&mut self, linear: &Linear, value: i64) -> Result<(), Box<dyn Error>> {
+        if !linear.is_constant() {
+            let constraint = Constraint::equals(linear, value).ok_or("Symbolic overflow")?;
+            self.constrain(constraint);
+        }
+
+        Ok(())
+    }
+
+    fn mode(&self, parameter: u32) -> Result<i64, Box<dyn Error>> {
+        match self.read(self.pc) / 10i64.pow(parameter + 1) % 10 {
+            mode @ 0..=2 => Ok(mode),
+            _ => Err("Unknown parameter mode".into()),
+        }
+    }
+
+    fn address(&self, parameter: u32) -> Result<Option<usize>, Box<dyn Error>> {
+        let value = self.read(self.pc + parameter as usize);
+
+        Ok(match self.mode(parameter)? {
+            0 => Some(Machine::check_address(value)?),
+            2 => Some(Machine::check_address(
+                self.relative_base
+                    .checked_add(value)
+                    .ok_or("Relative address overflow")?,
+            )?),
+            _ => None,
+        })
+    }
+
+    fn value(&self, parameter: u32) -> Result<(i64, Option<Shadow>), Box<dyn Error>> {
+        Ok(match self.address(parameter)? {
+            Some(address) => (self.read(address), self.shadow.get(&address).cloned()),
+            None => (self.read(self.pc + parameter as usize), None),
+        })
+    }
+
+    fn linear(&mut self, parameter: u32) -> Result<(i64, Linear), Box<dyn Error>> {
+        let (value, shadow) = self.value(parameter)?;
+
+        Ok(match shadow {
+            Some(Shadow::Linear(linear)) => (value, linear),
+            Some(Shadow::Compare(constraint)) => {
+                self.constrain(if value != 0 {
+                    constraint
+                } else {
+                    constraint.negate()
+                });
+                (value, Linear::constant(value))
+            }
+            None => (value, Linear::constant(value)),
+        })
+    }
+
+    fn store(
+        &mut self,
+        parameter: u32,
+        value: i64,
+        linear: Option<Linear>,
+    ) -> Result<(), Box<dyn Error>> {
+        let address = self
+            .address(parameter)?
+            .ok_or("Invalid address mode for write")?;
+        let shadow = linear
+            .filter(|linear| !linear.is_constant())
+            .map(Shadow::Linear);
+
+        self.write(address, value, shadow)
+    }
+
+    fn compare(&mut self, relation: Relation) -> Result<(), Box<dyn Error>> {
+        let (a, a_linear) = self.linear(1)?;
+        let (b, b_linear) = self.linear(2)?;
+
+        let value = match relation {
+            Relation::Lt => a < b,
+            _ => a == b,
+        } as i64;
+
+        let address = self.address(3)?.ok_or("Invalid address mode for write")?;
+
+        let expr = a_linear.sub(&b_linear).ok_or("Symbolic overflow")?;
+        let shadow = if expr.is_constant() {
+            None
+        } else {
+            Some(Shadow::Compare(Constraint { expr, relation }))
+        };
+
+        self.write(address, value, shadow)?;
+        self.pc += 4;
+
+        Ok(())
+    }
+
+    fn branch(&mut self, jump_if_true: bool) -> Result<(), Box<dyn Error>> {
+        let (condition, shadow) = self.value(1)?;
+        let taken = (condition != 0) == jump_if_true;
+
+        let constraint = match shadow {
+            Some(Shadow::Compare(constraint)) if condition != 0 => Some(constraint),
+            Some(Shadow::Compare(constraint)) => Some(constraint.negate()),
+            Some(Shadow::Linear(expr)) => Some(Constraint {
+                expr,
+                relation: if condition != 0 {
+                    Relation::Ne
+                } else {
+                    Relation::Eq
+                },
+            }),
+            None => None,
+        };
+
+        if let Some(constraint) = constraint {
+            self.path.push(constraint);
+        }
+
+        if taken {
+            let (target, target_linear) = self.linear(2)?;
+            self.pin(&target_linear, target)?;
+            self.pc = Machine::check_address(target)?;
+        } else {
+            self.pc += 3;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn execute(
+    program: &[i64],
+    symbols: &[Symbol],
+    input: &[i64],
+    assignment: &[i64],
+) -> Result<Run, Box<dyn Error>> {
+    let mut machine = Machine {
+        pc: 0,
+        relative_base: 0,
+        memory: program.to_vec(),
+        shadow: HashMap::new(),
+        path: Vec::new(),
+    };
+
+    let mut input = input.to_vec();
+
+    for (variable, symbol) in symbols.iter().enumerate() {
+        match symbol.source {
+            Source::Input(position) => {
+                if position >= input.len() {
+                    input.resize(position + 1, 0);
+                }
+                input[position] = assignment[variable];
+            }
+            Source::Memory(address) => {
+                let shadow = Shadow::Linear(Linear::variable(variable));
+                machine.write(address, assignment[variable], Some(shadow))?;
+            }
+        }
+    }
+
+    let mut input_counter = 0;
+    let mut outputs = Vec::new();
+
+    let mut exit = Exit::OutOfFuel;
+
+    for _ in 0..FUEL {
+        match machine.read(machine.pc) % 100 {
+            1 | 2 => {
+                let (a, a_linear) = machine.linear(1)?;
+                let (b, b_linear) = machine.linear(2)?;
+
+                let (value, linear) = if machine.read(machine.pc) % 100 == 1 {
+                    (a.checked_add(b), a_linear.add(&b_linear))
+                } else if b_linear.is_constant() {
+                    (a.checked_mul(b), a_linear.scale(b))
+                } else {
+                    machine.pin(&a_linear, a)?;
+                    (a.checked_mul(b), b_linear.scale(a))
+                };
+
+                let value = value.ok_or("Arithmetic overflow")?;
+                let linear = linear.ok_or("Symbolic overflow")?;
+
+                machine.store(3, value, Some(linear))?;
+                machine.pc += 4;
+            }
+            3 => {
+                let value = match input.get(input_counter) {
+                    Some(value) => *value,
+                    None => {
+                        exit = Exit::NeedMoreInput;
+                        break;
+                    }
+                };
+
+                let linear = symbols
+                    .iter()
+                    .position(|symbol| symbol.source == Source::Input(input_counter))
+                    .map(Linear::variable);
+
+                machine.store(1, value, linear)?;
+                input_counter += 1;
+                machine.pc += 2;
+            }
+            4 => {
+                outputs.push(machine.linear(1)?.1);
+                machine.pc += 2;
+            }
+            5 => machine.branch(true)?,
+            6 => machine.branch(false)?,
+            7 => machine.compare(Relation::Lt)?,
+            8 => machine.compare(Relation::Eq)?,
+            9 => {
+                let (offset, linear) = machine.linear(1)?;
+                machine.pin(&linear, offset)?;
+
+                machine.relative_base = machine
+                    .relative_base
+                    .checked_add(offset)
+                    .ok_or("Relative address overflow")?;
+                machine.pc += 2;
+            }
+            99 => {
+                exit = Exit::Halt;
+                break;
+            }
+            _ => return Err("Unknown opcode".into()),
+        }
+    }
+
+    Ok(Run {
+        exit,
+        path: machine.path,
+        outputs,
+        memory: machine.memory,
+        shadow: machine.shadow,
+    })
+}
+
+pub fn search<G>(
+    program: &[i64],
+    symbols: &[Symbol],
+    input: &[i64],
+    goal: G,
+    max_runs: usize,
+) -> Result<Option<Vec<i64>>, Box<dyn Error>>
+where
+    G: Fn(&Run) -> Option<Vec<Constraint>>,
+{
+    let domains = symbols
+        .iter()
+        .map(|symbol| symbol.domain.clone())
+        .collect::<Vec<_>>();
+
+    let mut queue = vec![domains
+        .iter()
+        .map(|domain| *domain.start())
+        .collect::<Vec<_>>()];
+    let mut seen = HashSet::new();
+
+    for _ in 0..max_runs {
+        let assignment = match queue.pop() {
+            Some(assignment) => assignment,
+            None => break,
+        };
+
+        let run = execute(program, symbols, input, &assignment)?;
+
+        if let Some(goal_constraints) = goal(&run) {
+            let mut constraints = run.path.clone();
+            constraints.extend(goal_constraints);
+
+            if let Some(solution) = solve(&constraints, &domains) {
+                let check = execute(program, symbols, input, &solution)?;
+
+                if goal(&check).is_some_and(|constraints| {
+                    constraints
+                        .iter()
+                        .all(|constraint| constraint.holds(&solution))
+                }) {
+                    return Ok(Some(solution));
+                }
+            }
+        }
+
+        for i in 0..run.path.len() {
+            let mut prefix = run.path[..i].to_vec();
+            prefix.push(run.path[i].negate());
+
+            if !seen.insert(prefix.clone()) {
+                continue;
+            }
+
+            if let Some(assignment) = solve(&prefix, &domains) {
+                queue.push(assignment);
+            }
+        }
+    }
+
+    Ok(None)
+}
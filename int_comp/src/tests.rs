@@ -0,0 +1,472 @@
+use super::coverage::Coverage;
+use super::executor::{self, Executor};
+use super::fuzz;
+use super::memory::{self, Dump, Patch, Region};
+use super::profile::Profile;
+use super::session::{Event, Session};
+use super::symbolic::{self, Constraint, Linear, Source, Symbol};
+use super::{parse_program, IntcodeComputer, IntcodeOutput};
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use std::thread;
+use std::time::Duration;
+
+fn parse(input: &str) -> Vec<i64> {
+    input
+        .split(',')
+        .map(|v| v.trim().parse::<i64>().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_run() -> Result<(), Box<dyn Error>> {
+    {
+        let program = [
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+
+        let output = IntcodeComputer::new(&program).run(&[], None)?;
+
+        assert_eq!(output.data(), &program);
+    }
+
+    {
+        let program = [3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
+
+        let mut intcode = IntcodeComputer::new(&program);
+
+        assert!(matches!(
+            intcode.run(&[], None)?,
+            IntcodeOutput::NeedMoreInput
+        ));
+        assert_eq!(intcode.run(&[8], None)?.data(), &[1]);
+    }
+
+    {
+        let program = [3, 0, 4, 0, 3, 0, 4, 0, 99];
+
+        let mut intcode = IntcodeComputer::new(&program);
+
+        assert!(matches!(
+            intcode.run(&[5, 6], Some(1))?,
+            IntcodeOutput::Interrupt(ref out) if out == &[5]
+        ));
+        assert!(matches!(
+            intcode.run(&[6], Some(1))?,
+            IntcodeOutput::Interrupt(ref out) if out == &[6]
+        ));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_run_with_fuel() -> Result<(), Box<dyn Error>> {
+    let program = [1105, 1, 0];
+
+    assert!(IntcodeComputer::new(&program)
+        .run_with_fuel(&[], None, 1000)
+        .is_err());
+
+    let program = [3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
+    let output = IntcodeComputer::new(&program).run_with_fuel(&[8], None, 4)?;
+
+    assert_eq!(output.data(), &[1]);
+    assert!(IntcodeComputer::new(&program)
+        .run_with_fuel(&[8], None, 3)
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_run_async() -> Result<(), Box<dyn Error>> {
+    let program = [3, 0, 4, 0, 3, 0, 4, 0, 99];
+
+    let mut intcode = IntcodeComputer::new(&program);
+    let mut input = vec![7, 8].into_iter();
+    let mut output = Vec::new();
+
+    executor::block_on(intcode.run_async(
+        || {
+            let value = input.next();
+            async move { value }
+        },
+        |value| output.push(value),
+    ))?;
+
+    assert_eq!(output, &[7, 8]);
+
+    Ok(())
+}
+
+struct WakeLater {
+    polls: usize,
+}
+
+impl Future for WakeLater {
+    type Output = usize;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<usize> {
+        self.polls += 1;
+
+        if self.polls > 1 {
+            return Poll::Ready(self.polls);
+        }
+
+        let waker = cx.waker().clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            waker.wake();
+        });
+
+        Poll::Pending
+    }
+}
+
+#[test]
+fn test_executor_parks_until_woken() {
+    assert_eq!(executor::block_on(WakeLater { polls: 0 }), 2);
+
+    let polls = Cell::new(0);
+    let mut executor = Executor::new();
+
+    executor.spawn(async {
+        polls.set(WakeLater { polls: 0 }.await);
+    });
+    executor.run();
+
+    assert_eq!(polls.get(), 2);
+}
+
+#[test]
+fn test_network() {
+    let program = parse(include_str!("../../day_23_a/input"));
+
+    let mut computers = (0..50)
+        .map(|_| IntcodeComputer::new(&program))
+        .collect::<Vec<_>>();
+
+    let queues = Rc::new(RefCell::new(
+        (0..50)
+            .map(|address| VecDeque::from(vec![address]))
+            .collect::<Vec<_>>(),
+    ));
+    let first_nat_y = Rc::new(Cell::new(None));
+
+    let mut executor = Executor::new();
+
+    for (address, computer) in computers.iter_mut().enumerate() {
+        let queues = queues.clone();
+        let first_nat_y = first_nat_y.clone();
+
+        executor.spawn(async move {
+            let mut packet = Vec::new();
+
+            computer
+                .run_async(
+                    || {
+                        let value = if first_nat_y.get().is_some() {
+                            None
+                        } else {
+                            Some(queues.borrow_mut()[address].pop_front().unwrap_or(-1))
+                        };
+
+                        async move {
+                            if value == Some(-1) {
+                                executor::yield_now().await;
+                            }
+                            value
+                        }
+                    },
+                    |value| {
+                        packet.push(value);
+
+                        if packet.len() == 3 {
+                            let destination = packet[0] as usize;
+
+                            if destination == 255 {
+                                if first_nat_y.get().is_none() {
+                                    first_nat_y.set(Some(packet[2]));
+                                }
+                            } else {
+                                queues.borrow_mut()[destination].extend(&packet[1..]);
+                            }
+
+                            packet.clear();
+                        }
+                    },
+                )
+                .await
+                .unwrap();
+        });
+    }
+
+    executor.run();
+
+    assert_eq!(first_nat_y.get(), Some(22877));
+}
+
+#[test]
+fn test_invalid_memory_access() {
+    for program in &[
+        vec![4, -1, 99],
+        vec![4, 2_000_000, 99],
+        vec![204, -3, 99],
+        vec![109, -10, 1201, 0, 0, 0, 99],
+        vec![1105, 1, -7],
+        vec![1101, i64::MAX, 1, 0, 99],
+        vec![1, 0, 0],
+    ] {
+        assert!(IntcodeComputer::new(program).run(&[], None).is_err());
+    }
+}
+
+#[test]
+fn test_fuzz() {
+    let mut rng = fuzz::Rng::new(2019);
+
+    for _ in 0..2000 {
+        let len = rng.range(1, 64) as usize;
+        let program = fuzz::generate_program(&mut rng, len);
+        let input = (0..rng.below(4))
+            .map(|_| rng.range(-10, 10))
+            .collect::<Vec<_>>();
+
+        fuzz::differential(&program, &input).unwrap();
+    }
+}
+
+#[test]
+fn test_profile() -> Result<(), Box<dyn Error>> {
+    let program = [109, 5, 1101, 1, 1, 100, 109, -5, 99];
+
+    let mut profile = Profile::new();
+    IntcodeComputer::new(&program).run_traced(&[], None, &mut profile)?;
+
+    assert_eq!(profile.instructions, 4);
+    assert_eq!(profile.pc_counts[&2], 1);
+    assert_eq!(profile.opcode_counts[&9], 2);
+    assert_eq!(profile.folded_stacks(), "main 2\nmain;fn_0 2\n");
+    assert_eq!(profile.frame_counts(), vec![(None, 4, 2), (Some(0), 2, 2)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_coverage() -> Result<(), Box<dyn Error>> {
+    let program = [3, 10, 1005, 10, 7, 104, 0, 104, 1, 99, 0];
+
+    let mut first = Coverage::new();
+    IntcodeComputer::new(&program).run_traced(&[0], None, &mut first)?;
+
+    let mut second = Coverage::new();
+    IntcodeComputer::new(&program).run_traced(&[1], None, &mut second)?;
+
+    assert_eq!(
+        first.executed.keys().copied().collect::<Vec<_>>(),
+        vec![0, 2, 5, 7, 9]
+    );
+    assert_eq!(first.branches[&2], (0, 1));
+    assert_eq!(second.branches[&2], (1, 0));
+
+    let diff = first.diff(&second);
+
+    assert_eq!(diff.only_first.iter().copied().collect::<Vec<_>>(), vec![5]);
+    assert!(diff.only_second.is_empty());
+    assert_eq!(diff.branches[&2], ("F".to_string(), "T".to_string()));
+
+    assert_eq!(
+        first.annotate_diff(&second, &program),
+        "*      0  in [10]\n\
+         *      2  jt [10], 7    ; F -> T\n\
+         -      5  out 0\n\
+         *      7  out 1\n\
+         *      9  halt\n\
+         \x20     10  data 0\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_symbolic_branch() -> Result<(), Box<dyn Error>> {
+    let program = [
+        3, 30, 1002, 30, 3, 31, 1001, 31, 4, 31, 1008, 31, 19, 32, 1005, 32, 20, 104, 0, 99, 104,
+        1, 99,
+    ];
+
+    let symbols = [Symbol {
+        source: Source::Input(0),
+        domain: -100..=100,
+    }];
+
+    let solution = symbolic::search(
+        &program,
+        &symbols,
+        &[],
+        |run| Some(vec![Constraint::equals(run.outputs.first()?, 1)?]),
+        10,
+    )?;
+
+    assert_eq!(solution, Some(vec![5]));
+
+    Ok(())
+}
+
+#[test]
+fn test_symbolic_pins_concretized_values() -> Result<(), Box<dyn Error>> {
+    let program = [3, 9, 105, 1, 9, 99, 104, 7, 99, 0];
+
+    let symbols = [Symbol {
+        source: Source::Input(0),
+        domain: 0..=10,
+    }];
+
+    let run = symbolic::execute(&program, &symbols, &[], &[6])?;
+    assert_eq!(run.outputs, [Linear::constant(7)]);
+    assert_eq!(
+        run.path,
+        [Constraint::equals(&Linear::variable(0), 6).unwrap()]
+    );
+
+    assert_eq!(Constraint::equals(&Linear::constant(i64::MIN), 1), None);
+    assert!(symbolic::execute(&[1101, 1, 1, 1_000_000_000_000, 99], &[], &[], &[]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_symbolic_noun_verb() -> Result<(), Box<dyn Error>> {
+    let program = parse(include_str!("../../day_2_b/input"));
+
+    let symbols = [
+        Symbol {
+            source: Source::Memory(1),
+            domain: 0..=99,
+        },
+        Symbol {
+            source: Source::Memory(2),
+            domain: 0..=99,
+        },
+    ];
+
+    let solution = symbolic::search(
+        &program,
+        &symbols,
+        &[],
+        |run| Some(vec![Constraint::equals(&run.memory(0), 19690720)?]),
+        1,
+    )?;
+
+    assert_eq!(solution, Some(vec![71, 95]));
+
+    Ok(())
+}
+
+#[test]
+fn test_session_replay() -> Result<(), Box<dyn Error>> {
+    let program = [3, 20, 1002, 20, 2, 20, 4, 20, 3, 20, 4, 20, 99];
+
+    let mut session = Session::new();
+    let mut intcode = IntcodeComputer::new(&program);
+    intcode.run_traced(&[21], None, &mut session)?;
+    intcode.run_traced(&[5], None, &mut session)?;
+
+    assert_eq!(
+        session.events,
+        vec![
+            Event::Input { step: 1, value: 21 },
+            Event::Output { step: 3, value: 42 },
+            Event::Input { step: 4, value: 5 },
+            Event::Output { step: 5, value: 5 },
+        ]
+    );
+
+    let loaded = session.to_string().parse::<Session>()?;
+    assert_eq!(loaded, session);
+    assert!(loaded.replay(&program)?.is_none());
+
+    let mut tampered = loaded;
+    tampered.events[3] = Event::Output { step: 5, value: 6 };
+
+    let divergence = tampered.replay(&program)?.unwrap();
+    assert_eq!(divergence.index, 3);
+    assert_eq!(divergence.actual, Some(Event::Output { step: 5, value: 5 }));
+
+    Ok(())
+}
+
+#[test]
+fn test_memory_dump() -> Result<(), Box<dyn Error>> {
+    let program = [3, 20, 1002, 20, 2, 21, 99];
+
+    let mut intcode = IntcodeComputer::new(&program);
+    let before = intcode.dump();
+    intcode.run(&[21], None)?;
+    let after = intcode.dump();
+
+    assert_eq!(before.memory, program);
+    assert_eq!(after.pc, 6);
+    assert_eq!(after.to_string().parse::<Dump>()?, after);
+
+    assert_eq!(
+        memory::diff(&before, &after),
+        vec![Region {
+            start: 20,
+            before: vec![0, 0],
+            after: vec![21, 42],
+        }]
+    );
+
+    let patch = "# double again\n5: 22\n21: 0".parse::<Patch>()?;
+    assert_eq!(patch.pokes, vec![(5, 22), (21, 0)]);
+
+    let mut patched = IntcodeComputer::new(&program);
+    patched.apply_patch(&patch)?;
+    patched.run(&[21], None)?;
+
+    assert_eq!(patched.dump().memory[20..], [21, 0, 42]);
+    assert!(patched.poke(1 << 30, 0).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_memory_diff_context() -> Result<(), Box<dyn Error>> {
+    let program = [1101, 1, 2, 9, 1101, 3, 4, 10, 99, 0, 0];
+
+    let mut intcode = IntcodeComputer::new(&program);
+    let before = intcode.dump();
+
+    assert!(intcode.run_steps(&[], 1)?.is_none());
+    assert_eq!(intcode.dump().pc, 4);
+    assert_eq!(intcode.dump().memory[9], 3);
+
+    intcode.run(&[], None)?;
+    let after = intcode.dump();
+
+    let diff = memory::format_diff(&before, &after);
+    let context = diff
+        .lines()
+        .filter(|line| line.starts_with("- "))
+        .collect::<Vec<_>>();
+
+    assert_eq!(diff.lines().nth(1), Some("@@ 9..11 @@"));
+    assert!(context[0].starts_with("-      4  add"));
+    assert!(context[1].starts_with("-      8  halt"));
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_program() {
+    assert_eq!(parse_program("1,0, 2,99\n").unwrap(), [1, 0, 2, 99]);
+    assert_eq!(parse_program("").unwrap(), []);
+    assert!(parse_program("NOT A T\n").is_err());
+}
@@ -0,0 +1,325 @@
+use int_comp::{IntcodeComputer, IntcodeOutput, Trace};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+pub const NAT: i64 = 255;
+pub const SIZE: usize = 50;
+pub const MAX_ROUNDS: u64 = 100_000;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Event {
+    pub round: u64,
+    pub from: i64,
+    pub to: i64,
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Event {
+    pub fn kind(&self) -> &'static str {
+        if self.from == NAT {
+            "wakeup"
+        } else if self.to == NAT {
+            "nat"
+        } else {
+            "packet"
+        }
+    }
+
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.round,
+            self.kind(),
+            self.from,
+            self.to,
+            self.x,
+            self.y
+        )
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"round\":{},\"kind\":\"{}\",\"from\":{},\"to\":{},\"x\":{},\"y\":{}}}",
+            self.round,
+            self.kind(),
+            self.from,
+            self.to,
+            self.x,
+            self.y
+        )
+    }
+}
+
+#[derive(Copy, Clone, Default, Eq, PartialEq, Debug)]
+pub struct NodeStats {
+    pub sent: u64,
+    pub received: u64,
+    pub idle_rounds: u64,
+}
+
+struct Consumed(usize);
+
+impl Trace for Consumed {
+    fn input(&mut self, _value: i64) {
+        self.0 += 1;
+    }
+}
+
+pub struct Network {
+    nodes: Vec<IntcodeComputer>,
+    queues: Vec<Vec<i64>>,
+    stats: Vec<NodeStats>,
+    events: Vec<Event>,
+    nat: Option<(i64, i64)>,
+    last_wakeup: Option<i64>,
+    repeated_wakeup: Option<i64>,
+    round: u64,
+    idle_rounds: u64,
+    idle: bool,
+}
+
+impl Network {
+    pub fn new(program: &[i64], size: usize) -> Network {
+        Network {
+            nodes: vec![IntcodeComputer::new(program); size],
+            queues: (0..size as i64).map(|address| vec![address]).collect(),
+            stats: vec![NodeStats::default(); size],
+            events: Vec::new(),
+            nat: None,
+            last_wakeup: None,
+            repeated_wakeup: None,
+            round: 0,
+            idle_rounds: 0,
+            idle: false,
+        }
+    }
+
+    pub fn rounds(&self) -> u64 {
+        self.round
+    }
+
+    pub fn idle_rounds(&self) -> u64 {
+        self.idle_rounds
+    }
+
+    pub fn stats(&self) -> &[NodeStats] {
+        &self.stats
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    pub fn first_nat_packet(&self) -> Option<&Event> {
+        self.events.iter().find(|event| event.to == NAT)
+    }
+
+    pub fn wakeups(&self) -> impl Iterator<Item = &Event> {
+        self.events.iter().filter(|event| event.from == NAT)
+    }
+
+    pub fn first_repeated_wakeup(&self) -> Option<i64> {
+        self.repeated_wakeup
+    }
+
+    fn deliver(&mut self, event: Event) -> Result<(), Box<dyn Error>> {
+        self.events.push(event);
+
+        if event.from == NAT {
+            if self.repeated_wakeup.is_none() && self.last_wakeup == Some(event.y) {
+                self.repeated_wakeup = Some(event.y);
+            }
+
+            self.last_wakeup = Some(event.y);
+        }
+
+        if event.to == NAT {
+            self.nat = Some((event.x, event.y));
+            return Ok(());
+        }
+
+        let to = event.to as usize;
+
+        if event.to < 0 || to >= self.queues.len() {
+            return Err(format!("Packet to unknown address: {}", event.to).into());
+        }
+
+        self.queues[to].extend(&[event.x, event.y]);
+        self.stats[to].received += 1;
+
+        Ok(())
+    }
+
+    pub fn round(&mut self) -> Result<(), Box<dyn Error>> {
+        self.round += 1;
+
+        if let (true, Some((x, y))) = (self.idle, self.nat) {
+            self.deliver(Event {
+                round: self.round,
+                from: NAT,
+                to: 0,
+                x,
+                y,
+            })?;
+        }
+
+        let mut sent = false;
+
+        for node in 0..self.nodes.len() {
+            let mut input = self.queues[node].drain(..).collect::<Vec<_>>();
+
+            if input.is_empty() {
+                input.push(-1);
+                self.stats[node].idle_rounds += 1;
+            }
+
+            let mut consumed = Consumed(0);
+
+            loop {
+                let pending = &input[consumed.0.min(input.len())..];
+
+                match self.nodes[node].run_traced(pending, Some(3), &mut consumed)? {
+                    IntcodeOutput::Interrupt(packet) => {
+                        sent = true;
+                        self.stats[node].sent += 1;
+                        self.deliver(Event {
+                            round: self.round,
+                            from: node as i64,
+                            to: packet[0],
+                            x: packet[1],
+                            y: packet[2],
+                        })?;
+                    }
+                    IntcodeOutput::NeedMoreInput => break,
+                    IntcodeOutput::Halt(_) => return Err(format!("Node {} halted", node).into()),
+                }
+            }
+        }
+
+        self.idle = !sent && self.queues.iter().all(|queue| queue.is_empty());
+
+        if self.idle {
+            self.idle_rounds += 1;
+        }
+
+        Ok(())
+    }
+
+    pub fn run_until<F: Fn(&Network) -> bool>(&mut self, done: F) -> Result<(), Box<dyn Error>> {
+        while !done(self) {
+            if self.round >= MAX_ROUNDS {
+                return Err(format!("Network still running after {} rounds", MAX_ROUNDS).into());
+            }
+
+            self.round()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("round,kind,from,to,x,y\n");
+
+        for event in &self.events {
+            csv.push_str(&event.to_csv());
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    pub fn to_jsonl(&self) -> String {
+        self.events
+            .iter()
+            .map(|event| format!("{}\n", event.to_json()))
+            .collect()
+    }
+
+    pub fn export(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        match Path::new(path).extension().and_then(|ext| ext.to_str()) {
+            Some("csv") => fs::write(path, self.to_csv())?,
+            Some("jsonl") => fs::write(path, self.to_jsonl())?,
+            _ => return Err(format!("Unknown log format: {}", path).into()),
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Rounds: {} ({} idle), events: {}",
+            self.round,
+            self.idle_rounds,
+            self.events.len()
+        )?;
+
+        writeln!(
+            f,
+            "{:>4} {:>8} {:>8} {:>8}",
+            "node", "sent", "received", "idle"
+        )?;
+
+        for (node, stats) in self.stats.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>4} {:>8} {:>8} {:>8}",
+                node, stats.sent, stats.received, stats.idle_rounds
+            )?;
+        }
+
+        writeln!(f, "NAT wakeups:")?;
+
+        for wakeup in self.wakeups() {
+            writeln!(
+                f,
+                "  round {:>5}: x={} y={}",
+                wakeup.round, wakeup.x, wakeup.y
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Network, NAT, SIZE};
+    use std::error::Error;
+
+    fn program() -> Vec<i64> {
+        include_str!("../../day_23_a/input")
+            .split(',')
+            .map(|v| v.trim().parse::<i64>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_network() -> Result<(), Box<dyn Error>> {
+        let mut network = Network::new(&program(), SIZE);
+        network.run_until(|network| network.first_repeated_wakeup().is_some())?;
+
+        assert_eq!(network.first_nat_packet().map(|event| event.y), Some(22877));
+        assert_eq!(network.first_repeated_wakeup(), Some(15210));
+
+        let sent = network.stats().iter().map(|stats| stats.sent).sum::<u64>();
+        let received = network
+            .stats()
+            .iter()
+            .map(|stats| stats.received)
+            .sum::<u64>();
+        let to_nat = network.events().iter().filter(|e| e.to == NAT).count() as u64;
+
+        assert_eq!(sent, received - network.wakeups().count() as u64 + to_nat);
+        assert_eq!(network.to_csv().lines().count(), network.events().len() + 1);
+        assert_eq!(network.to_jsonl().lines().count(), network.events().len());
+        assert!(network.idle_rounds() >= network.wakeups().count() as u64);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+use std::error::Error;
+use std::str::FromStr;
+
+mod font;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Font {
+    Small,
+    Large,
+}
+
+impl Font {
+    pub fn height(self) -> usize {
+        match self {
+            Font::Small => 6,
+            Font::Large => 10,
+        }
+    }
+
+    pub fn from_height(height: usize) -> Option<Font> {
+        match height {
+            6 => Some(Font::Small),
+            10 => Some(Font::Large),
+            _ => None,
+        }
+    }
+
+    fn glyphs(self) -> &'static [(char, &'static str)] {
+        match self {
+            Font::Small => font::SMALL,
+            Font::Large => font::LARGE,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Bitmap {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<bool>,
+}
+
+impl Bitmap {
+    pub fn new(width: usize, pixels: Vec<bool>) -> Bitmap {
+        let height = pixels.len().checked_div(width).unwrap_or(0);
+
+        Bitmap {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.pixels[x + y * self.width]
+    }
+
+    fn row_empty(&self, y: usize) -> bool {
+        (0..self.width).all(|x| !self.get(x, y))
+    }
+
+    fn column_empty(&self, x: usize, rows: &[usize]) -> bool {
+        rows.iter().all(|y| !self.get(x, *y))
+    }
+}
+
+impl FromStr for Bitmap {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut pixels = Vec::with_capacity(width * lines.len());
+
+        for line in lines {
+            let len = pixels.len();
+            pixels.extend(line.chars().map(|ch| ch == '#'));
+            pixels.resize(len + width, false);
+        }
+
+        Ok(Bitmap::new(width, pixels))
+    }
+}
+
+fn trim_glyph(glyph: &str) -> String {
+    let rows = glyph.lines().collect::<Vec<_>>();
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    let used = (0..width)
+        .filter(|x| rows.iter().any(|row| row.as_bytes().get(*x) == Some(&b'#')))
+        .collect::<Vec<_>>();
+
+    let (first, last) = match (used.first(), used.last()) {
+        (Some(first), Some(last)) => (*first, *last),
+        _ => return String::new(),
+    };
+
+    rows.iter()
+        .map(|row| &row[first..=last])
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn recognize(bitmap: &Bitmap) -> Result<String, Box<dyn Error>> {
+    let rows = (0..bitmap.height)
+        .filter(|y| !bitmap.row_empty(*y))
+        .collect::<Vec<_>>();
+
+    let height = match (rows.first(), rows.last()) {
+        (Some(first), Some(last)) => last - first + 1,
+        _ => return Ok(String::new()),
+    };
+
+    let font = Font::from_height(height)
+        .ok_or_else(|| format!("No font with letter height {}", height))?;
+
+    recognize_with(font, bitmap)
+}
+
+pub fn recognize_with(font: Font, bitmap: &Bitmap) -> Result<String, Box<dyn Error>> {
+    let first = (0..bitmap.height)
+        .find(|y| !bitmap.row_empty(*y))
+        .unwrap_or(0);
+
+    if first + font.height() > bitmap.height {
+        return Err("Bitmap is too small for the font".into());
+    }
+
+    let rows = (first..first + font.height()).collect::<Vec<_>>();
+
+    let mut text = String::new();
+    let mut x = 0;
+
+    while x < bitmap.width {
+        if bitmap.column_empty(x, &rows) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+
+        while x < bitmap.width && !bitmap.column_empty(x, &rows) {
+            x += 1;
+        }
+
+        let glyph = rows
+            .iter()
+            .map(|y| {
+                (start..x)
+                    .map(|x| if bitmap.get(x, *y) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let letter = font
+            .glyphs()
+            .iter()
+            .find(|(_, template)| trim_glyph(template) == glyph)
+            .map(|(letter, _)| *letter)
+            .ok_or_else(|| format!("Unrecognized glyph at column {}:\n{}", start, glyph))?;
+
+        text.push(letter);
+    }
+
+    Ok(text)
+}
+
+pub fn recognize_str(input: &str) -> Result<String, Box<dyn Error>> {
+    recognize(&input.parse()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::font::{LARGE, SMALL};
+    use super::{recognize, recognize_str, recognize_with, Bitmap, Font};
+
+    fn render(font: &[(char, &str)], text: &str, spacing: usize) -> String {
+        let glyphs = text
+            .chars()
+            .map(|ch| font.iter().find(|(letter, _)| *letter == ch).unwrap().1)
+            .map(|glyph| glyph.lines().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        (0..glyphs[0].len())
+            .map(|y| {
+                glyphs
+                    .iter()
+                    .map(|glyph| format!("{}{}", glyph[y], ".".repeat(spacing)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_small_font() {
+        let text = SMALL.iter().map(|(letter, _)| *letter).collect::<String>();
+
+        assert_eq!(recognize_str(&render(SMALL, &text, 1)).unwrap(), text);
+    }
+
+    #[test]
+    fn test_large_font() {
+        let text = LARGE.iter().map(|(letter, _)| *letter).collect::<String>();
+
+        assert_eq!(recognize_str(&render(LARGE, &text, 2)).unwrap(), text);
+    }
+
+    #[test]
+    fn test_padding() {
+        let art = format!("\n\n{}\n\n", render(SMALL, "HI", 3));
+        let bitmap = art.parse::<Bitmap>().unwrap();
+
+        assert_eq!(recognize(&bitmap).unwrap(), "HI");
+        assert_eq!(recognize_with(Font::Small, &bitmap).unwrap(), "HI");
+        assert!(recognize_with(Font::Large, &bitmap).is_err());
+    }
+
+    #[test]
+    fn test_unrecognized() {
+        assert!(recognize_str("#.#\n.#.\n#.#\n.#.\n#.#\n.#.").is_err());
+        assert!(recognize_str("###\n###").is_err());
+        assert_eq!(recognize_str("...\n...").unwrap(), "");
+    }
+}
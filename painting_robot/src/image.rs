@@ -0,0 +1,126 @@
+use std::fmt::Write;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<bool>,
+}
+
+impl Image {
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.pixels[x + y * self.width]
+    }
+
+    pub fn scaled(&self, scale: usize) -> Image {
+        let width = self.width * scale;
+        let height = self.height * scale;
+
+        let pixels = (0..width * height)
+            .map(|i| self.get((i % width) / scale, (i / width) / scale))
+            .collect();
+
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn to_pbm(&self) -> String {
+        let mut output = String::new();
+
+        writeln!(output, "P1").unwrap();
+        writeln!(output, "{} {}", self.width, self.height).unwrap();
+
+        for row in self.pixels.chunks(self.width.max(1)) {
+            let line = row
+                .iter()
+                .map(|white| if *white { "0" } else { "1" })
+                .collect::<Vec<_>>();
+
+            writeln!(output, "{}", line.join(" ")).unwrap();
+        }
+
+        output
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity((self.width + 1) * self.height);
+
+        for row in self.pixels.chunks(self.width.max(1)) {
+            raw.push(0);
+            raw.extend(row.iter().map(|white| if *white { 255 } else { 0 }));
+        }
+
+        let mut header = Vec::new();
+        header.extend(&(self.width as u32).to_be_bytes());
+        header.extend(&(self.height as u32).to_be_bytes());
+        header.extend(&[8, 0, 0, 0, 0]);
+
+        let mut output = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+        chunk(&mut output, b"IHDR", &header);
+        chunk(&mut output, b"IDAT", &zlib_stored(&raw));
+        chunk(&mut output, b"IEND", &[]);
+
+        output
+    }
+}
+
+fn chunk(output: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    output.extend(&(data.len() as u32).to_be_bytes());
+
+    let start = output.len();
+    output.extend(kind);
+    output.extend(data);
+
+    let crc = crc32(&output[start..]);
+    output.extend(&crc.to_be_bytes());
+}
+
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut output = vec![0x78, 0x01];
+    let blocks = data.chunks(0xffff).collect::<Vec<_>>();
+
+    if blocks.is_empty() {
+        output.extend(&[1, 0, 0, 0xff, 0xff]);
+    }
+
+    for (i, block) in blocks.iter().enumerate() {
+        let len = block.len() as u16;
+
+        output.push((i + 1 == blocks.len()) as u8);
+        output.extend(&len.to_le_bytes());
+        output.extend(&(!len).to_le_bytes());
+        output.extend(*block);
+    }
+
+    output.extend(&adler32(data).to_be_bytes());
+    output
+}
+
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+
+    for byte in data {
+        crc ^= *byte as u32;
+
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+
+    !crc
+}
+
+pub(crate) fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+
+    (b << 16) | a
+}
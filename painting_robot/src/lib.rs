@@ -0,0 +1,390 @@
+use int_comp::{IntcodeComputer, IntcodeOutput};
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+pub mod image;
+
+use image::Image;
+
+pub type Position = (i32, i32);
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Color {
+    Black,
+    White,
+}
+
+impl Color {
+    pub fn as_i64(self) -> i64 {
+        match self {
+            Color::Black => 0,
+            Color::White => 1,
+        }
+    }
+
+    pub fn from_i64(value: i64) -> Result<Color, Box<dyn Error>> {
+        match value {
+            0 => Ok(Color::Black),
+            1 => Ok(Color::White),
+            _ => Err(format!("Unknown color: {}", value).into()),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "black" => Ok(Color::Black),
+            "white" => Ok(Color::White),
+            _ => Err(format!("Unknown start color: {}", s).into()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Heading {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Heading {
+    pub fn turn_left(self) -> Heading {
+        match self {
+            Heading::Up => Heading::Left,
+            Heading::Left => Heading::Down,
+            Heading::Down => Heading::Right,
+            Heading::Right => Heading::Up,
+        }
+    }
+
+    pub fn turn_right(self) -> Heading {
+        match self {
+            Heading::Up => Heading::Right,
+            Heading::Right => Heading::Down,
+            Heading::Down => Heading::Left,
+            Heading::Left => Heading::Up,
+        }
+    }
+
+    pub fn offset(self) -> Position {
+        match self {
+            Heading::Up => (0, 1),
+            Heading::Right => (1, 0),
+            Heading::Down => (0, -1),
+            Heading::Left => (-1, 0),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Paint {
+    pub step: u64,
+    pub position: Position,
+    pub color: Color,
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct Hull {
+    pub panels: HashMap<Position, Color>,
+    pub visits: HashMap<Position, u32>,
+    pub history: Vec<Paint>,
+}
+
+impl Hull {
+    pub fn get(&self, position: Position) -> Option<Color> {
+        self.panels.get(&position).copied()
+    }
+
+    pub fn painted(&self) -> usize {
+        self.panels.len()
+    }
+
+    pub fn visits(&self, position: Position) -> u32 {
+        self.visits.get(&position).copied().unwrap_or(0)
+    }
+
+    pub fn most_visited(&self) -> Option<(Position, u32)> {
+        self.visits
+            .iter()
+            .max_by_key(|(position, count)| (**count, std::cmp::Reverse(**position)))
+            .map(|(position, count)| (*position, *count))
+    }
+
+    pub fn bounds(&self) -> Option<(Position, Position)> {
+        let white = self
+            .panels
+            .iter()
+            .filter(|(_, color)| **color == Color::White)
+            .map(|(position, _)| *position)
+            .collect::<Vec<_>>();
+
+        let min_x = white.iter().map(|p| p.0).min()?;
+        let max_x = white.iter().map(|p| p.0).max()?;
+        let min_y = white.iter().map(|p| p.1).min()?;
+        let max_y = white.iter().map(|p| p.1).max()?;
+
+        Some(((min_x, min_y), (max_x, max_y)))
+    }
+
+    pub fn image(&self) -> Image {
+        let ((min_x, min_y), (max_x, max_y)) = match self.bounds() {
+            Some(bounds) => bounds,
+            None => {
+                return Image {
+                    width: 0,
+                    height: 0,
+                    pixels: Vec::new(),
+                }
+            }
+        };
+
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+
+        let pixels = (min_y..=max_y)
+            .rev()
+            .flat_map(|y| (min_x..=max_x).map(move |x| (x, y)))
+            .map(|position| self.get(position) == Some(Color::White))
+            .collect();
+
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn read(&self) -> Result<String, Box<dyn Error>> {
+        let image = self.image();
+
+        ocr::recognize(&ocr::Bitmap::new(image.width, image.pixels))
+    }
+
+    pub fn export(&self, path: &str, scale: usize) -> Result<(), Box<dyn Error>> {
+        let image = self.image().scaled(scale.max(1));
+
+        match Path::new(path).extension().and_then(|ext| ext.to_str()) {
+            Some("pbm") => fs::write(path, image.to_pbm())?,
+            Some("png") => fs::write(path, image.to_png())?,
+            _ => return Err(format!("Unknown image format: {}", path).into()),
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Hull {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let image = self.image();
+
+        for row in image.pixels.chunks(image.width.max(1)) {
+            let line = row
+                .iter()
+                .map(|white| if *white { '#' } else { ' ' })
+                .collect::<String>();
+
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Robot {
+    int_comp: IntcodeComputer,
+    position: Position,
+    heading: Heading,
+    hull: Hull,
+    steps: u64,
+}
+
+impl Robot {
+    pub fn new(program: &[i64], start: Color) -> Robot {
+        let mut hull = Hull::default();
+
+        if start == Color::White {
+            hull.panels.insert((0, 0), start);
+        }
+
+        Robot {
+            int_comp: IntcodeComputer::new(program),
+            position: (0, 0),
+            heading: Heading::Up,
+            hull,
+            steps: 0,
+        }
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    pub fn heading(&self) -> Heading {
+        self.heading
+    }
+
+    pub fn hull(&self) -> &Hull {
+        &self.hull
+    }
+
+    pub fn step(&mut self) -> Result<bool, Box<dyn Error>> {
+        let camera = self.hull.get(self.position).unwrap_or(Color::Black);
+
+        let (color, turn) = match self.int_comp.run(&[camera.as_i64()], Some(2))? {
+            IntcodeOutput::Interrupt(output) => (Color::from_i64(output[0])?, output[1]),
+            IntcodeOutput::Halt(_) => return Ok(false),
+            IntcodeOutput::NeedMoreInput => return Err("Robot needs more input".into()),
+        };
+
+        self.steps += 1;
+        *self.hull.visits.entry(self.position).or_insert(0) += 1;
+        self.hull.panels.insert(self.position, color);
+        self.hull.history.push(Paint {
+            step: self.steps,
+            position: self.position,
+            color,
+        });
+
+        self.heading = match turn {
+            0 => self.heading.turn_left(),
+            1 => self.heading.turn_right(),
+            _ => return Err(format!("Unknown turn: {}", turn).into()),
+        };
+
+        let (dx, dy) = self.heading.offset();
+        self.position = (self.position.0 + dx, self.position.1 + dy);
+
+        Ok(true)
+    }
+
+    pub fn run(mut self) -> Result<Hull, Box<dyn Error>> {
+        while self.step()? {}
+
+        Ok(self.hull)
+    }
+}
+
+pub fn paint(input: &str, start: Color) -> Result<Hull, Box<dyn Error>> {
+    let program = input
+        .split(',')
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Robot::new(&program, start).run()
+}
+
+fn arg(name: &str) -> Option<String> {
+    env::args().skip_while(|arg| arg != name).nth(1)
+}
+
+pub struct Options {
+    pub start: Color,
+    pub stats: bool,
+    pub export: Option<(String, usize)>,
+}
+
+impl Options {
+    pub fn from_args(start: Color) -> Result<Options, Box<dyn Error>> {
+        let start = arg("--start").map_or(Ok(start), |start| start.parse())?;
+        let stats = env::args().any(|arg| arg == "--stats");
+
+        let export = match arg("--export") {
+            Some(path) => Some((path, arg("--scale").map_or(Ok(1), |scale| scale.parse())?)),
+            None => None,
+        };
+
+        Ok(Options {
+            start,
+            stats,
+            export,
+        })
+    }
+
+    pub fn report(&self, hull: &Hull) -> Result<(), Box<dyn Error>> {
+        if self.stats {
+            println!("Paint operations: {}", hull.history.len());
+
+            if let Some((position, count)) = hull.most_visited() {
+                println!("Most visited: {:?} ({} times)", position, count);
+            }
+        }
+
+        if let Some((path, scale)) = &self.export {
+            hull.export(path, *scale)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::image::{adler32, crc32};
+    use super::{paint, Color, Hull, Robot};
+    use std::error::Error;
+
+    fn program() -> Vec<i64> {
+        include_str!("../../day_11_a/input")
+            .split(',')
+            .map(|v| v.trim().parse::<i64>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut hull = Hull::default();
+        hull.panels.insert((-3, 5), Color::White);
+        hull.panels.insert((-1, 4), Color::White);
+        hull.panels.insert((-2, 4), Color::Black);
+
+        assert_eq!(hull.to_string(), "#\n  #\n");
+
+        let image = hull.image();
+        assert_eq!((image.width, image.height), (3, 2));
+        assert_eq!(image.to_pbm(), "P1\n3 2\n0 1 1\n1 1 0\n");
+        assert_eq!(image.scaled(2).pixels.len(), 24);
+
+        let png = image.to_png();
+        assert_eq!(&png[1..4], b"PNG");
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+    }
+
+    #[test]
+    fn test_paint() -> Result<(), Box<dyn Error>> {
+        let hull = Robot::new(&program(), Color::Black).run()?;
+
+        assert_eq!(hull.painted(), 2428);
+        assert_eq!(hull.history.len() as u32, hull.visits.values().sum::<u32>());
+
+        let hull = paint(include_str!("../../day_11_a/input"), "white".parse()?)?;
+        let image = hull.image();
+
+        assert_eq!(image.height, 6);
+        assert_eq!(hull.read()?, "RJLFBUCU");
+        assert_eq!(
+            hull.to_string().lines().next(),
+            Some("###    ## #    #### ###  #  #  ##  #  #")
+        );
+        assert_eq!(
+            hull.visits((0, 0)),
+            hull.history.iter().filter(|p| p.position == (0, 0)).count() as u32
+        );
+
+        Ok(())
+    }
+}
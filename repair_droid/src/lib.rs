@@ -0,0 +1,321 @@
+use grid::{Bounds, Pos, SparseGrid};
+use int_comp::{IntcodeComputer, IntcodeOutput};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+pub mod render;
+
+pub type Position = Pos;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Direction {
+    North,
+    South,
+    West,
+    East,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+    ];
+
+    pub fn as_i64(self) -> i64 {
+        match self {
+            Direction::North => 1,
+            Direction::South => 2,
+            Direction::West => 3,
+            Direction::East => 4,
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+            Direction::East => Direction::West,
+        }
+    }
+
+    pub fn apply(self, (x, y): Position) -> Position {
+        match self {
+            Direction::North => (x, y + 1),
+            Direction::South => (x, y - 1),
+            Direction::West => (x - 1, y),
+            Direction::East => (x + 1, y),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Tile {
+    Wall,
+    Ground,
+    GroundOxygen,
+}
+
+impl Tile {
+    pub fn from_status(status: i64) -> Option<Tile> {
+        match status {
+            0 => Some(Tile::Wall),
+            1 => Some(Tile::Ground),
+            2 => Some(Tile::GroundOxygen),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Tile::Wall => '#',
+            Tile::Ground => '.',
+            Tile::GroundOxygen => 'O',
+        }
+    }
+}
+
+pub struct Droid {
+    int_comp: IntcodeComputer,
+    position: Position,
+}
+
+impl Droid {
+    pub fn new(program: &[i64]) -> Droid {
+        Droid {
+            int_comp: IntcodeComputer::new(program),
+            position: (0, 0),
+        }
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    pub fn step(&mut self, direction: Direction) -> Result<Tile, Box<dyn Error>> {
+        let status = match self.int_comp.run(&[direction.as_i64()], Some(1))? {
+            IntcodeOutput::Interrupt(output) => output[0],
+            _ => return Err("Droid stopped responding".into()),
+        };
+
+        let tile = Tile::from_status(status).ok_or(format!("Unknown status: {}", status))?;
+
+        if tile != Tile::Wall {
+            self.position = direction.apply(self.position);
+        }
+
+        Ok(tile)
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct Map {
+    pub tiles: SparseGrid<Tile>,
+}
+
+impl Map {
+    pub fn new() -> Map {
+        Map::default()
+    }
+
+    pub fn get(&self, position: Position) -> Option<Tile> {
+        self.tiles.get(position).copied()
+    }
+
+    pub fn oxygen(&self) -> Option<Position> {
+        self.tiles.find(|tile| *tile == Tile::GroundOxygen)
+    }
+
+    fn is_open(&self, position: Position) -> bool {
+        matches!(
+            self.get(position),
+            Some(Tile::Ground) | Some(Tile::GroundOxygen)
+        )
+    }
+
+    fn open_neighbors(&self, position: Position) -> Vec<Position> {
+        grid::neighbors4(position)
+            .filter(|next| self.is_open(*next))
+            .collect()
+    }
+
+    pub fn distances(&self, from: Position) -> HashMap<Position, usize> {
+        if !self.is_open(from) {
+            return HashMap::new();
+        }
+
+        search::bfs(vec![from], |position| self.open_neighbors(*position)).costs
+    }
+
+    pub fn path(&self, from: Position, to: Position) -> Option<Vec<Position>> {
+        search::a_star(
+            from,
+            |position| {
+                self.open_neighbors(*position)
+                    .into_iter()
+                    .map(|next| (next, 1))
+            },
+            |position| grid::manhattan(*position, to),
+            |position| *position == to,
+        )
+        .map(|(path, _)| path)
+    }
+
+    pub fn shortest_path(&self, from: Position, to: Position) -> Option<usize> {
+        self.distances(from).get(&to).copied()
+    }
+
+    pub fn fill_time(&self) -> Option<usize> {
+        let oxygen = self.oxygen()?;
+        self.distances(oxygen).values().max().copied()
+    }
+
+    pub fn bounds(&self) -> (Position, Position) {
+        let bounds = self
+            .tiles
+            .bounds()
+            .unwrap_or_else(|| Bounds::new((0, 0), (0, 0)));
+
+        (bounds.min, bounds.max)
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds();
+
+        for y in (min_y..=max_y).rev() {
+            let line = (min_x..=max_x)
+                .map(|x| match self.get((x, y)) {
+                    _ if (x, y) == (0, 0) => 'S',
+                    Some(tile) => tile.to_char(),
+                    None => ' ',
+                })
+                .collect::<String>();
+
+            writeln!(f, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Exploration {
+    pub map: Map,
+    pub steps: u64,
+}
+
+impl Exploration {
+    pub fn oxygen_distance(&self) -> Option<usize> {
+        self.map.shortest_path((0, 0), self.map.oxygen()?)
+    }
+
+    pub fn fill_time(&self) -> Option<usize> {
+        self.map.fill_time()
+    }
+}
+
+pub fn explore<F>(program: &[i64], mut on_step: F) -> Result<Exploration, Box<dyn Error>>
+where
+    F: FnMut(&Map, Position) -> Result<(), Box<dyn Error>>,
+{
+    let mut droid = Droid::new(program);
+    let mut map = Map::new();
+    let mut path: Vec<Direction> = Vec::new();
+    let mut steps = 0;
+
+    map.tiles.insert(droid.position(), Tile::Ground);
+    on_step(&map, droid.position())?;
+
+    loop {
+        let position = droid.position();
+        let unexplored = Direction::ALL
+            .iter()
+            .copied()
+            .find(|direction| map.get(direction.apply(position)).is_none());
+
+        let direction = match unexplored {
+            Some(direction) => direction,
+            None => match path.pop() {
+                Some(direction) => direction.reverse(),
+                None => break,
+            },
+        };
+
+        let tile = droid.step(direction)?;
+        steps += 1;
+
+        if unexplored.is_some() {
+            map.tiles.insert(direction.apply(position), tile);
+
+            if tile != Tile::Wall {
+                path.push(direction);
+            }
+        } else if tile == Tile::Wall {
+            return Err("Backtracking hit a wall".into());
+        }
+
+        on_step(&map, droid.position())?;
+    }
+
+    Ok(Exploration { map, steps })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{explore, Map, Tile};
+    use std::error::Error;
+
+    fn program() -> Vec<i64> {
+        include_str!("../../day_15_a/input")
+            .split(',')
+            .map(|v| v.trim().parse::<i64>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_fill_time() {
+        let area = [" ##   ", "#..## ", "#.#..#", "#.O.# ", " ###  "];
+        let mut map = Map::new();
+
+        for (row, line) in area.iter().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                let tile = match ch {
+                    '#' => Tile::Wall,
+                    '.' => Tile::Ground,
+                    'O' => Tile::GroundOxygen,
+                    _ => continue,
+                };
+
+                map.tiles.insert((x as i32, -(row as i32)), tile);
+            }
+        }
+
+        assert_eq!(map.fill_time(), Some(4));
+        assert_eq!(map.shortest_path((1, -1), (2, -3)), Some(3));
+        assert_eq!(
+            map.path((1, -1), (2, -3)),
+            Some(vec![(1, -1), (1, -2), (1, -3), (2, -3)])
+        );
+        assert_eq!(map.path((1, -1), (0, 0)), None);
+    }
+
+    #[test]
+    fn test_explore() -> Result<(), Box<dyn Error>> {
+        let mut renders = 0;
+        let exploration = explore(&program(), |_, _| {
+            renders += 1;
+            Ok(())
+        })?;
+
+        assert_eq!(renders, exploration.steps + 1);
+        assert_eq!(exploration.oxygen_distance(), Some(242));
+        assert_eq!(exploration.fill_time(), Some(276));
+
+        Ok(())
+    }
+}
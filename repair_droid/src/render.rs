@@ -0,0 +1,52 @@
+use super::{Direction, Exploration, Position, Tile};
+use crossterm::{
+    cursor,
+    style::{style, Color, PrintStyledContent},
+    terminal, ExecutableCommand,
+};
+use std::error::Error;
+use std::io::stdout;
+
+pub fn draw(offset: Position, pos: Position, ch: char, color: Color) -> Result<(), Box<dyn Error>> {
+    stdout()
+        .execute(cursor::MoveTo(
+            (pos.0 + offset.0) as u16,
+            (pos.1 + offset.1) as u16,
+        ))?
+        .execute(PrintStyledContent(style(ch).with(color)))?;
+
+    Ok(())
+}
+
+pub fn explore(program: &[i64], offset: Position) -> Result<Exploration, Box<dyn Error>> {
+    stdout().execute(terminal::Clear(terminal::ClearType::All))?;
+
+    let mut last_pos = (0, 0);
+
+    let exploration = super::explore(program, |map, pos| {
+        draw(offset, last_pos, '.', Color::Grey)?;
+
+        for direction in &Direction::ALL {
+            let neighbor = direction.apply(pos);
+
+            if let Some(tile) = map.get(neighbor) {
+                let color = match tile {
+                    Tile::GroundOxygen => Color::Blue,
+                    _ => Color::Grey,
+                };
+
+                draw(offset, neighbor, tile.to_char(), color)?;
+            }
+        }
+
+        draw(offset, pos, 'D', Color::Grey)?;
+        last_pos = pos;
+
+        Ok(())
+    })?;
+
+    let ((_, _), (_, max_y)) = exploration.map.bounds();
+    stdout().execute(cursor::MoveTo(0, (max_y + offset.1 + 1) as u16))?;
+
+    Ok(exploration)
+}
@@ -0,0 +1,317 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+#[derive(Clone, Debug)]
+pub struct Search<S, C> {
+    pub costs: HashMap<S, C>,
+    pub came_from: HashMap<S, S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new() -> Search<S, C> {
+        Search {
+            costs: HashMap::new(),
+            came_from: HashMap::new(),
+        }
+    }
+
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    pub fn path_to(&self, goal: &S) -> Option<Vec<S>> {
+        if self.costs.contains_key(goal) {
+            Some(reconstruct_path(&self.came_from, goal.clone()))
+        } else {
+            None
+        }
+    }
+}
+
+pub fn reconstruct_path<S: Clone + Eq + Hash>(came_from: &HashMap<S, S>, goal: S) -> Vec<S> {
+    let mut path = vec![goal];
+
+    while let Some(previous) = came_from.get(path.last().unwrap()) {
+        path.push(previous.clone());
+    }
+
+    path.reverse();
+    path
+}
+
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+fn explore<S, C, N, I, H, G>(
+    start: S,
+    mut neighbors: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> (Option<S>, Search<S, C>)
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search::new();
+    let mut open = BinaryHeap::new();
+
+    search.costs.insert(start.clone(), C::default());
+    open.push(Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    });
+
+    while let Some(Entry { cost, state, .. }) = open.pop() {
+        if search.costs.get(&state).is_some_and(|best| cost > *best) {
+            continue;
+        }
+
+        if is_goal(&state) {
+            return (Some(state), search);
+        }
+
+        for (next, step) in neighbors(&state) {
+            let next_cost = cost + step;
+
+            if search.costs.get(&next).is_none_or(|best| next_cost < *best) {
+                search.costs.insert(next.clone(), next_cost);
+                search.came_from.insert(next.clone(), state.clone());
+
+                open.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    (None, search)
+}
+
+pub fn a_star<S, C, N, I, H, G>(
+    start: S,
+    neighbors: N,
+    heuristic: H,
+    is_goal: G,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let (goal, search) = explore(start, neighbors, heuristic, is_goal);
+    let goal = goal?;
+
+    Some((
+        reconstruct_path(&search.came_from, goal.clone()),
+        search.costs[&goal],
+    ))
+}
+
+pub fn dijkstra<S, C, N, I, G>(start: S, neighbors: N, is_goal: G) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    a_star(start, neighbors, |_| C::default(), is_goal)
+}
+
+pub fn dijkstra_all<S, C, N, I>(start: S, neighbors: N) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    explore(start, neighbors, |_| C::default(), |_| false).1
+}
+
+pub fn bfs<S, N, I, T>(starts: T, mut neighbors: N) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    T: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.costs.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let cost = search.costs[&state];
+
+        for next in neighbors(&state) {
+            if !search.costs.contains_key(&next) {
+                search.costs.insert(next.clone(), cost + 1);
+                search.came_from.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{a_star, bfs, dijkstra, dijkstra_all, reconstruct_path};
+    use std::collections::HashMap;
+
+    const MAZE: &str = "#########
+#S..#...#
+#.#.#.#.#
+#.#...#G#
+#########";
+
+    fn open_tiles(maze: &str) -> HashMap<(i32, i32), char> {
+        maze.lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|(_, ch)| *ch != '#')
+                    .map(move |(x, ch)| ((x as i32, y as i32), ch))
+            })
+            .collect()
+    }
+
+    fn find(tiles: &HashMap<(i32, i32), char>, tile: char) -> (i32, i32) {
+        *tiles.iter().find(|(_, ch)| **ch == tile).unwrap().0
+    }
+
+    fn steps(tiles: &HashMap<(i32, i32), char>, pos: &(i32, i32)) -> Vec<((i32, i32), u32)> {
+        [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .iter()
+            .map(|(dx, dy)| (pos.0 + dx, pos.1 + dy))
+            .filter(|next| tiles.contains_key(next))
+            .map(|next| (next, 1))
+            .collect()
+    }
+
+    #[test]
+    fn test_a_star() {
+        let tiles = open_tiles(MAZE);
+        let (start, goal) = (find(&tiles, 'S'), find(&tiles, 'G'));
+
+        let (path, cost) = a_star(
+            start,
+            |pos| steps(&tiles, pos),
+            |pos| ((goal.0 - pos.0).abs() + (goal.1 - pos.1).abs()) as u32,
+            |pos| *pos == goal,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 12);
+        assert_eq!(path.len(), 13);
+        assert_eq!((path[0], path[12]), (start, goal));
+        assert!(path
+            .windows(2)
+            .all(|pair| (pair[0].0 - pair[1].0).abs() + (pair[0].1 - pair[1].1).abs() == 1));
+
+        assert_eq!(
+            a_star(start, |pos| steps(&tiles, pos), |_| 0, |pos| *pos == (0, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_dijkstra_weighted() {
+        let edges = [('a', 'b', 7), ('a', 'c', 9), ('a', 'f', 14), ('b', 'c', 10)]
+            .iter()
+            .chain(&[('b', 'd', 15), ('c', 'd', 11), ('c', 'f', 2), ('d', 'e', 6)])
+            .chain(&[('e', 'f', 9)])
+            .flat_map(|&(a, b, cost)| vec![(a, b, cost), (b, a, cost)])
+            .collect::<Vec<_>>();
+
+        let neighbors = |node: &char| {
+            edges
+                .iter()
+                .filter(|(from, _, _)| from == node)
+                .map(|(_, to, cost)| (*to, *cost))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            dijkstra('a', neighbors, |node| *node == 'e'),
+            Some((vec!['a', 'c', 'f', 'e'], 20))
+        );
+
+        let search = dijkstra_all('a', neighbors);
+        assert_eq!(search.cost(&'d'), Some(20));
+        assert_eq!(search.path_to(&'d'), Some(vec!['a', 'c', 'd']));
+        assert_eq!(search.path_to(&'x'), None);
+    }
+
+    #[test]
+    fn test_bfs() {
+        let tiles = open_tiles(MAZE);
+        let neighbors = |pos: &(i32, i32)| steps(&tiles, pos).into_iter().map(|(next, _)| next);
+
+        let search = bfs(vec![find(&tiles, 'S')], neighbors);
+        assert_eq!(search.cost(&find(&tiles, 'G')), Some(12));
+        assert_eq!(search.costs.len(), tiles.len());
+
+        let search = bfs(vec![find(&tiles, 'S'), find(&tiles, 'G')], neighbors);
+        assert_eq!(search.costs.values().max(), Some(&6));
+        assert_eq!(search.path_to(&(3, 3)).unwrap().len(), 5);
+        assert_eq!(search.path_to(&(3, 3)).unwrap()[0], find(&tiles, 'S'));
+    }
+
+    #[test]
+    fn test_reconstruct_path() {
+        let came_from = [(3, 2), (2, 1)].iter().copied().collect::<HashMap<_, _>>();
+
+        assert_eq!(reconstruct_path(&came_from, 3), [1, 2, 3]);
+        assert_eq!(reconstruct_path(&came_from, 1), [1]);
+    }
+}
@@ -0,0 +1,260 @@
+use super::{Instruction, Op, Register};
+use std::error::Error;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Expr {
+    Const(bool),
+    Sensor(u8),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    pub fn eval(&self, sensors: u16) -> bool {
+        match self {
+            Expr::Const(value) => *value,
+            Expr::Sensor(sensor) => sensors & (1 << sensor) != 0,
+            Expr::Not(expr) => !expr.eval(sensors),
+            Expr::And(a, b) => a.eval(sensors) && b.eval(sensors),
+            Expr::Or(a, b) => a.eval(sensors) || b.eval(sensors),
+        }
+    }
+
+    pub fn sensors(&self) -> usize {
+        match self {
+            Expr::Const(_) => 0,
+            Expr::Sensor(sensor) => *sensor as usize + 1,
+            Expr::Not(expr) => expr.sensors(),
+            Expr::And(a, b) | Expr::Or(a, b) => a.sensors().max(b.sensors()),
+        }
+    }
+
+    pub fn compile(&self) -> Option<Vec<Instruction>> {
+        let mut instructions = Vec::new();
+
+        if linear(self, Register::J, &mut instructions) {
+            return Some(instructions);
+        }
+
+        match self {
+            Expr::Not(expr) => {
+                let mut instructions = expr.compile()?;
+                instructions.push(Instruction::new(Op::Not, Register::J, Register::J));
+                Some(instructions)
+            }
+            Expr::And(a, b) | Expr::Or(a, b) => {
+                let op = match self {
+                    Expr::And(_, _) => Op::And,
+                    _ => Op::Or,
+                };
+
+                [(a, b), (b, a)]
+                    .iter()
+                    .filter_map(|(first, second)| {
+                        let mut instructions = first.compile()?;
+
+                        if !linear(second, Register::T, &mut instructions) {
+                            return None;
+                        }
+
+                        instructions.push(Instruction::new(op, Register::T, Register::J));
+                        Some(instructions)
+                    })
+                    .min_by_key(|instructions| instructions.len())
+            }
+            _ => None,
+        }
+    }
+}
+
+fn literal(expr: &Expr) -> Option<(u8, bool)> {
+    match expr {
+        Expr::Sensor(sensor) => Some((*sensor, true)),
+        Expr::Not(expr) => match **expr {
+            Expr::Sensor(sensor) => Some((sensor, false)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn linear(expr: &Expr, reg: Register, instructions: &mut Vec<Instruction>) -> bool {
+    let not = |x| Instruction::new(Op::Not, x, reg);
+
+    match expr {
+        Expr::Const(value) => {
+            instructions.push(not(Register::Sensor(0)));
+            let op = if *value { Op::Or } else { Op::And };
+            instructions.push(Instruction::new(op, Register::Sensor(0), reg));
+        }
+        Expr::Sensor(sensor) => {
+            instructions.push(not(Register::Sensor(*sensor)));
+            instructions.push(not(reg));
+        }
+        Expr::Not(inner) => match **inner {
+            Expr::Sensor(sensor) => instructions.push(not(Register::Sensor(sensor))),
+            _ => {
+                if !linear(inner, reg, instructions) {
+                    return false;
+                }
+
+                instructions.push(not(reg));
+            }
+        },
+        Expr::And(a, b) | Expr::Or(a, b) => {
+            let op = match expr {
+                Expr::And(_, _) => Op::And,
+                _ => Op::Or,
+            };
+
+            let (rest, (sensor, positive)) = match (literal(a), literal(b)) {
+                (_, Some(lit)) => (a, lit),
+                (Some(lit), _) => (b, lit),
+                _ => return false,
+            };
+
+            if !linear(rest, reg, instructions) {
+                return false;
+            }
+
+            let sensor = Register::Sensor(sensor);
+
+            if positive {
+                instructions.push(Instruction::new(op, sensor, reg));
+            } else {
+                let op = if op == Op::And { Op::Or } else { Op::And };
+
+                instructions.push(not(reg));
+                instructions.push(Instruction::new(op, sensor, reg));
+                instructions.push(not(reg));
+            }
+        }
+    }
+
+    true
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expr::Const(value) => write!(f, "{}", *value as u8),
+            Expr::Sensor(sensor) => write!(f, "{}", (b'A' + sensor) as char),
+            Expr::Not(expr) => write!(f, "!{}", expr),
+            Expr::And(a, b) => write!(f, "({} & {})", a, b),
+            Expr::Or(a, b) => write!(f, "({} | {})", a, b),
+        }
+    }
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&mut self) -> Option<char> {
+        while self.chars.peek().is_some_and(|ch| ch.is_whitespace()) {
+            self.chars.next();
+        }
+
+        self.chars.peek().copied()
+    }
+
+    fn or(&mut self) -> Result<Expr, Box<dyn Error>> {
+        let mut expr = self.and()?;
+
+        while self.peek() == Some('|') {
+            self.chars.next();
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, Box<dyn Error>> {
+        let mut expr = self.unary()?;
+
+        while self.peek() == Some('&') {
+            self.chars.next();
+            expr = Expr::And(Box::new(expr), Box::new(self.unary()?));
+        }
+
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr, Box<dyn Error>> {
+        let ch = self.peek().ok_or("Unexpected end of formula")?;
+        self.chars.next();
+
+        match ch {
+            '!' => Ok(Expr::Not(Box::new(self.unary()?))),
+            '(' => {
+                let expr = self.or()?;
+
+                if self.peek() != Some(')') {
+                    return Err("Expected )".into());
+                }
+
+                self.chars.next();
+                Ok(expr)
+            }
+            '0' => Ok(Expr::Const(false)),
+            '1' => Ok(Expr::Const(true)),
+            'A'..='I' => Ok(Expr::Sensor(ch as u8 - b'A')),
+            _ => Err(format!("Unexpected character in formula: {}", ch).into()),
+        }
+    }
+}
+
+impl FromStr for Expr {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            chars: input.chars().peekable(),
+        };
+
+        let expr = parser.or()?;
+
+        match parser.peek() {
+            None => Ok(expr),
+            Some(ch) => Err(format!("Unexpected character in formula: {}", ch).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Mode, Script};
+    use super::Expr;
+
+    #[test]
+    fn test_parse() {
+        let expr = "!(A & B & C) & D".parse::<Expr>().unwrap();
+
+        assert_eq!(expr.to_string(), "(!((A & B) & C) & D)");
+        assert_eq!(expr.sensors(), 4);
+        assert!(expr.eval(0b1000));
+        assert!(!expr.eval(0b1111));
+
+        assert!("A &".parse::<Expr>().is_err());
+        assert!("(A | B".parse::<Expr>().is_err());
+        assert!("A B".parse::<Expr>().is_err());
+        assert!("J".parse::<Expr>().is_err());
+    }
+
+    #[test]
+    fn test_compile() {
+        for formula in &["!(A & B & C) & D", "(A | B) & (C | !D)", "!A", "1", "0"] {
+            let expr = formula.parse::<Expr>().unwrap();
+            let script = Script::new(expr.compile().unwrap(), Mode::Walk).unwrap();
+
+            for sensors in 0..16 {
+                assert_eq!(script.jumps(sensors), expr.eval(sensors), "{}", formula);
+            }
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use int_comp::{IntcodeComputer, IntcodeOutput};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+pub mod expr;
+pub mod synth;
+
+pub const MAX_INSTRUCTIONS: usize = 15;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Register {
+    Sensor(u8),
+    T,
+    J,
+}
+
+impl Register {
+    pub fn is_writable(self) -> bool {
+        self == Register::T || self == Register::J
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Register::Sensor(sensor) => write!(f, "{}", (b'A' + sensor) as char),
+            Register::T => write!(f, "T"),
+            Register::J => write!(f, "J"),
+        }
+    }
+}
+
+impl FromStr for Register {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.as_bytes() {
+            [b'T'] => Ok(Register::T),
+            [b'J'] => Ok(Register::J),
+            [ch @ b'A'..=b'I'] => Ok(Register::Sensor(ch - b'A')),
+            _ => Err(format!("Invalid register: {}", input).into()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Op {
+    And,
+    Or,
+    Not,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Instruction {
+    pub op: Op,
+    pub x: Register,
+    pub y: Register,
+}
+
+impl Instruction {
+    pub fn new(op: Op, x: Register, y: Register) -> Instruction {
+        Instruction { op, x, y }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let op = match self.op {
+            Op::And => "AND",
+            Op::Or => "OR",
+            Op::Not => "NOT",
+        };
+
+        write!(f, "{} {} {}", op, self.x, self.y)
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = Box<dyn Error>;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let parts = line.split_whitespace().collect::<Vec<_>>();
+
+        let (op, x, y) = match parts.as_slice() {
+            [op, x, y] => (op, x, y),
+            _ => return Err(format!("Invalid instruction: {}", line).into()),
+        };
+
+        let op = match *op {
+            "AND" => Op::And,
+            "OR" => Op::Or,
+            "NOT" => Op::Not,
+            _ => return Err(format!("Unknown operation: {}", op).into()),
+        };
+
+        Ok(Instruction::new(op, x.parse()?, y.parse()?))
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Mode {
+    Walk,
+    Run,
+}
+
+impl Mode {
+    pub fn sensors(self) -> usize {
+        match self {
+            Mode::Walk => 4,
+            Mode::Run => 9,
+        }
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Mode::Walk => write!(f, "WALK"),
+            Mode::Run => write!(f, "RUN"),
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Script {
+    pub instructions: Vec<Instruction>,
+    pub mode: Mode,
+}
+
+impl Script {
+    pub fn new(instructions: Vec<Instruction>, mode: Mode) -> Result<Script, Box<dyn Error>> {
+        let script = Script { instructions, mode };
+        script.validate()?;
+
+        Ok(script)
+    }
+
+    pub fn validate(&self) -> Result<(), Box<dyn Error>> {
+        if self.instructions.len() > MAX_INSTRUCTIONS {
+            return Err(format!(
+                "Script has {} instructions, the limit is {}",
+                self.instructions.len(),
+                MAX_INSTRUCTIONS
+            )
+            .into());
+        }
+
+        for (line, instruction) in self.instructions.iter().enumerate() {
+            if !instruction.y.is_writable() {
+                return Err(format!("Line {}: {} is not writable", line + 1, instruction.y).into());
+            }
+
+            if let Register::Sensor(sensor) = instruction.x {
+                if sensor as usize >= self.mode.sensors() {
+                    return Err(format!(
+                        "Line {}: sensor {} is not available in {} mode",
+                        line + 1,
+                        instruction.x,
+                        self.mode
+                    )
+                    .into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn jumps(&self, sensors: u16) -> bool {
+        let mut t = false;
+        let mut j = false;
+
+        for instruction in &self.instructions {
+            let x = match instruction.x {
+                Register::Sensor(sensor) => sensors & (1 << sensor) != 0,
+                Register::T => t,
+                Register::J => j,
+            };
+
+            let y = match instruction.y {
+                Register::T => &mut t,
+                Register::J => &mut j,
+                Register::Sensor(_) => continue,
+            };
+
+            *y = match instruction.op {
+                Op::And => x && *y,
+                Op::Or => x || *y,
+                Op::Not => !x,
+            };
+        }
+
+        j
+    }
+
+    pub fn truth_table(&self) -> Vec<bool> {
+        (0..1 << self.mode.sensors())
+            .map(|sensors| self.jumps(sensors))
+            .collect()
+    }
+
+    pub fn simulate(&self, hull: &str) -> Result<(), usize> {
+        let hull = hull.trim().as_bytes();
+        let ground = |x: usize| hull.get(x).is_none_or(|tile| *tile == b'#');
+
+        let mut x = 0;
+
+        while x < hull.len() {
+            if !ground(x) {
+                return Err(x);
+            }
+
+            let sensors = (0..self.mode.sensors())
+                .filter(|sensor| ground(x + 1 + sensor))
+                .fold(0, |sensors, sensor| sensors | 1 << sensor);
+
+            x += if self.jumps(sensors) { 4 } else { 1 };
+        }
+
+        Ok(())
+    }
+
+    pub fn to_input(&self) -> Vec<i64> {
+        self.to_string().chars().map(|c| c as i64).collect()
+    }
+}
+
+impl fmt::Display for Script {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for instruction in &self.instructions {
+            writeln!(f, "{}", instruction)?;
+        }
+
+        writeln!(f, "{}", self.mode)
+    }
+}
+
+impl FromStr for Script {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut lines = input
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>();
+
+        let mode = match lines.pop() {
+            Some("WALK") => Mode::Walk,
+            Some("RUN") => Mode::Run,
+            _ => return Err("Script must end with WALK or RUN".into()),
+        };
+
+        let instructions = lines
+            .iter()
+            .enumerate()
+            .map(|(line, text)| {
+                text.parse()
+                    .map_err(|err| format!("Line {}: {}", line + 1, err).into())
+            })
+            .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+
+        Script::new(instructions, mode)
+    }
+}
+
+pub fn run(input: &str, script: &Script) -> Result<IntcodeOutput, Box<dyn Error>> {
+    let program = input
+        .split(',')
+        .map(|v| v.trim().parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut incode = IntcodeComputer::new(&program);
+
+    incode.run(&script.to_input(), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run, Mode, Script};
+
+    const WALK: &str = "NOT A T
+NOT T T
+AND B T
+AND C T
+NOT D J
+OR J T
+NOT T J
+WALK
+";
+
+    #[test]
+    fn test_parse() {
+        let script = WALK.parse::<Script>().unwrap();
+
+        assert_eq!(script.mode, Mode::Walk);
+        assert_eq!(script.instructions.len(), 7);
+        assert_eq!(script.to_string(), WALK);
+
+        assert!("NOT A T".parse::<Script>().is_err());
+        assert!("NOT A B\nWALK".parse::<Script>().is_err());
+        assert!("NOT E J\nWALK".parse::<Script>().is_err());
+        assert!("NOT E J\nRUN".parse::<Script>().is_ok());
+        assert!("XOR A J\nRUN".parse::<Script>().is_err());
+        assert!(format!("{}RUN", "NOT A J\n".repeat(16))
+            .parse::<Script>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let script = WALK.parse::<Script>().unwrap();
+
+        assert!(!script.jumps(0b1111));
+        assert!(script.jumps(0b1110));
+        assert!(!script.jumps(0b0110));
+        assert_eq!(script.truth_table().iter().filter(|j| **j).count(), 7);
+
+        assert_eq!(script.simulate("#####.###########"), Ok(()));
+        assert_eq!(script.simulate("#####...#########"), Ok(()));
+        assert_eq!(script.simulate("#..#.#"), Err(1));
+    }
+
+    #[test]
+    fn test_run() {
+        let script = WALK.parse::<Script>().unwrap();
+        let output = run(include_str!("../../day_21_a/input"), &script).unwrap();
+
+        assert_eq!(output.data().last(), Some(&19359533));
+    }
+}
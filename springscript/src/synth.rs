@@ -0,0 +1,206 @@
+use super::expr::Expr;
+use super::{Mode, Script, MAX_INSTRUCTIONS};
+use std::collections::BTreeSet;
+use std::error::Error;
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+struct Implicant {
+    value: u16,
+    mask: u16,
+}
+
+impl Implicant {
+    fn covers(self, minterm: u16) -> bool {
+        minterm & !self.mask == self.value
+    }
+
+    fn to_expr(self, sensors: usize) -> Expr {
+        (0..sensors as u8)
+            .filter(|sensor| self.mask & (1 << sensor) == 0)
+            .map(|sensor| {
+                if self.value & (1 << sensor) != 0 {
+                    Expr::Sensor(sensor)
+                } else {
+                    Expr::Not(Box::new(Expr::Sensor(sensor)))
+                }
+            })
+            .fold(None, |expr, literal| match expr {
+                None => Some(literal),
+                Some(expr) => Some(Expr::And(Box::new(expr), Box::new(literal))),
+            })
+            .unwrap_or(Expr::Const(true))
+    }
+}
+
+fn prime_implicants(minterms: &[u16]) -> Vec<Implicant> {
+    let mut current = minterms
+        .iter()
+        .map(|value| Implicant {
+            value: *value,
+            mask: 0,
+        })
+        .collect::<BTreeSet<_>>();
+
+    let mut primes = BTreeSet::new();
+
+    while !current.is_empty() {
+        let mut next = BTreeSet::new();
+        let mut combined = BTreeSet::new();
+
+        for a in &current {
+            for b in &current {
+                let diff = a.value ^ b.value;
+
+                if a.mask == b.mask && a.value < b.value && diff.count_ones() == 1 {
+                    next.insert(Implicant {
+                        value: a.value & !diff,
+                        mask: a.mask | diff,
+                    });
+                    combined.insert(*a);
+                    combined.insert(*b);
+                }
+            }
+        }
+
+        primes.extend(current.difference(&combined).copied());
+        current = next;
+    }
+
+    primes.into_iter().collect()
+}
+
+pub fn minimize<F: Fn(u16) -> bool>(sensors: usize, truth: F) -> Expr {
+    let minterms = (0..1u16 << sensors)
+        .filter(|sensors| truth(*sensors))
+        .collect::<Vec<_>>();
+
+    if minterms.is_empty() {
+        return Expr::Const(false);
+    }
+
+    let primes = prime_implicants(&minterms);
+    let mut uncovered = minterms.into_iter().collect::<BTreeSet<_>>();
+    let mut cover = Vec::new();
+
+    while !uncovered.is_empty() {
+        let essential = uncovered.iter().find_map(|minterm| {
+            let covering = primes
+                .iter()
+                .filter(|prime| prime.covers(*minterm))
+                .collect::<Vec<_>>();
+
+            if covering.len() == 1 {
+                Some(*covering[0])
+            } else {
+                None
+            }
+        });
+
+        let best = essential.unwrap_or_else(|| {
+            *primes
+                .iter()
+                .max_by_key(|prime| uncovered.iter().filter(|m| prime.covers(**m)).count())
+                .unwrap()
+        });
+
+        uncovered.retain(|minterm| !best.covers(*minterm));
+        cover.push(best);
+    }
+
+    cover
+        .into_iter()
+        .map(|implicant| implicant.to_expr(sensors))
+        .fold(None, |expr, term| match expr {
+            None => Some(term),
+            Some(expr) => Some(Expr::Or(Box::new(expr), Box::new(term))),
+        })
+        .unwrap()
+}
+
+pub fn synthesize(expr: &Expr, mode: Mode) -> Result<Script, Box<dyn Error>> {
+    let sensors = mode.sensors();
+
+    if expr.sensors() > sensors {
+        return Err(format!("Formula uses sensors not available in {} mode", mode).into());
+    }
+
+    let negated = minimize(sensors, |s| !expr.eval(s));
+
+    let candidates = [
+        expr.clone(),
+        minimize(sensors, |s| expr.eval(s)),
+        Expr::Not(Box::new(negated)),
+    ];
+
+    let instructions = candidates
+        .iter()
+        .filter_map(|candidate| candidate.compile())
+        .min_by_key(|instructions| instructions.len())
+        .ok_or("Formula cannot be compiled with two registers")?;
+
+    if instructions.len() > MAX_INSTRUCTIONS {
+        return Err(format!(
+            "Shortest script has {} instructions, the limit is {}",
+            instructions.len(),
+            MAX_INSTRUCTIONS
+        )
+        .into());
+    }
+
+    let script = Script::new(instructions, mode)?;
+
+    if let Some(sensors) = (0..1 << sensors).find(|s| script.jumps(*s) != expr.eval(*s)) {
+        return Err(format!("Synthesized script disagrees on sensors {:09b}", sensors).into());
+    }
+
+    Ok(script)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Mode, Script};
+    use super::{minimize, synthesize};
+
+    const RUN: &str = "NOT I T
+NOT F J
+AND J T
+NOT E J
+OR J T
+NOT H J
+AND J T
+NOT A J
+NOT J J
+AND B J
+AND C J
+OR J T
+NOT D J
+OR J T
+NOT T J
+RUN
+";
+
+    #[test]
+    fn test_minimize() {
+        let expr = minimize(4, |s| s & 0b0111 != 0b0111 && s & 0b1000 != 0);
+
+        assert_eq!(expr.to_string(), "(((!C & D) | (!B & D)) | (!A & D))");
+    }
+
+    #[test]
+    fn test_synthesize() {
+        let walk = synthesize(&"!(A & B & C) & D".parse().unwrap(), Mode::Walk).unwrap();
+        assert_eq!(walk.instructions.len(), 6);
+
+        let hand_written = RUN.parse::<Script>().unwrap();
+        let run = synthesize(
+            &"!(A & B & C) & D & (H | E & (F | I))".parse().unwrap(),
+            Mode::Run,
+        )
+        .unwrap();
+
+        assert_eq!(run.truth_table(), hand_written.truth_table());
+        assert!(run.instructions.len() <= hand_written.instructions.len());
+
+        assert!(synthesize(&"E".parse().unwrap(), Mode::Walk).is_err());
+    }
+}
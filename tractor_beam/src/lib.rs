@@ -0,0 +1,214 @@
+use grid::DenseGrid;
+use int_comp::{IntcodeComputer, IntcodeOutput};
+use std::collections::HashMap;
+use std::error::Error;
+
+const PROBE_MEMORY: usize = 4096;
+const MAX_SLOPE: i64 = 10;
+const MAX_ROWS: i64 = 100_000;
+
+pub struct TractorBeam {
+    drone: IntcodeComputer,
+    cache: HashMap<(i64, i64), bool>,
+    rows: Vec<Option<(i64, i64)>>,
+    probes: u64,
+}
+
+impl TractorBeam {
+    pub fn new(program: &[i64]) -> TractorBeam {
+        TractorBeam {
+            drone: IntcodeComputer::with_memory_size(program, PROBE_MEMORY),
+            cache: HashMap::new(),
+            rows: Vec::new(),
+            probes: 0,
+        }
+    }
+
+    pub fn probes(&self) -> u64 {
+        self.probes
+    }
+
+    pub fn probe(&mut self, x: i64, y: i64) -> Result<bool, Box<dyn Error>> {
+        if x < 0 || y < 0 {
+            return Ok(false);
+        }
+
+        if let Some(pulled) = self.cache.get(&(x, y)) {
+            return Ok(*pulled);
+        }
+
+        let pulled = match self.drone.clone().run(&[x, y], Some(1))? {
+            IntcodeOutput::Interrupt(output) => output[0] == 1,
+            _ => return Err("Drone did not report".into()),
+        };
+
+        self.probes += 1;
+        self.cache.insert((x, y), pulled);
+
+        Ok(pulled)
+    }
+
+    pub fn row(&mut self, y: i64) -> Result<Option<(i64, i64)>, Box<dyn Error>> {
+        if y < 0 {
+            return Ok(None);
+        }
+
+        while self.rows.len() as i64 <= y {
+            let row = self.scan_row(self.rows.len() as i64)?;
+            self.rows.push(row);
+        }
+
+        Ok(self.rows[y as usize])
+    }
+
+    fn scan_row(&mut self, y: i64) -> Result<Option<(i64, i64)>, Box<dyn Error>> {
+        let previous = self.rows.iter().rev().find_map(|row| *row);
+        let (from, previous_end) = previous.unwrap_or((0, 0));
+
+        let mut start = None;
+
+        for x in from..=(y + 1) * MAX_SLOPE {
+            if self.probe(x, y)? {
+                start = Some(x);
+                break;
+            }
+        }
+
+        let start = match start {
+            Some(start) => start,
+            None => return Ok(None),
+        };
+
+        let mut end = start.max(previous_end);
+
+        if !self.probe(end, y)? {
+            end = start;
+        }
+
+        while self.probe(end + 1, y)? {
+            end += 1;
+        }
+
+        Ok(Some((start, end)))
+    }
+
+    pub fn count(&mut self, width: i64, height: i64) -> Result<usize, Box<dyn Error>> {
+        if width < 1 || height < 1 {
+            return Err(format!("Invalid area: {}x{}", width, height).into());
+        }
+
+        let mut count = 0;
+
+        for y in 0..height {
+            if let Some((start, end)) = self.row(y)? {
+                let (start, end) = (start.max(0), end.min(width - 1));
+
+                if end >= start {
+                    count += (end - start + 1) as usize;
+                }
+            }
+        }
+
+        Ok(count)
+    }
+
+    pub fn fits_square(&mut self, size: i64) -> Result<(i64, i64), Box<dyn Error>> {
+        if size < 1 {
+            return Err(format!("Invalid square size: {}", size).into());
+        }
+
+        for y in (size - 1)..MAX_ROWS {
+            let top = y - size + 1;
+
+            if let (Some((start, _)), Some((_, top_end))) = (self.row(y)?, self.row(top)?) {
+                if top_end >= start + size - 1 {
+                    return Ok((start, top));
+                }
+            }
+        }
+
+        Err(format!(
+            "No {0}x{0} square fits in the first {1} rows",
+            size, MAX_ROWS
+        )
+        .into())
+    }
+
+    pub fn render(&mut self, width: i64, height: i64) -> Result<String, Box<dyn Error>> {
+        if width < 1 || height < 1 {
+            return Err(format!("Invalid area: {}x{}", width, height).into());
+        }
+
+        let rows = (0..height)
+            .map(|y| self.row(y))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let grid = DenseGrid::from_fn(width as usize, height as usize, |(x, y)| {
+            match rows[y as usize] {
+                Some((start, end)) if x as i64 >= start && x as i64 <= end => '#',
+                _ => '.',
+            }
+        });
+
+        Ok(grid.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TractorBeam;
+    use std::error::Error;
+
+    fn program() -> Vec<i64> {
+        include_str!("../../day_19_a/input")
+            .split(',')
+            .map(|v| v.trim().parse::<i64>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_count() -> Result<(), Box<dyn Error>> {
+        let mut beam = TractorBeam::new(&program());
+        let count = beam.count(50, 50)?;
+
+        assert_eq!(count, 112);
+        assert!(beam.probes() < 50 * 50);
+
+        let mut brute_force = 0;
+
+        for y in 0..50 {
+            for x in 0..50 {
+                if beam.probe(x, y)? {
+                    brute_force += 1;
+                }
+            }
+        }
+
+        assert_eq!(brute_force, count);
+        assert_eq!(beam.render(50, 50)?.matches('#').count(), count);
+
+        assert!(beam.count(0, 50).is_err());
+        assert!(beam.count(50, -1).is_err());
+        assert!(beam.render(-5, 5).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fits_square() -> Result<(), Box<dyn Error>> {
+        let mut beam = TractorBeam::new(&program());
+
+        assert_eq!(beam.fits_square(1)?, (0, 0));
+        assert!(beam.fits_square(0).is_err());
+        assert!(beam.fits_square(-3).is_err());
+        assert_eq!(beam.fits_square(100)?, (1826, 1982));
+
+        let (x, y) = beam.fits_square(10)?;
+
+        for (dx, dy) in &[(0, 0), (9, 0), (0, 9), (9, 9)] {
+            assert!(beam.probe(x + dx, y + dy)?);
+        }
+
+        Ok(())
+    }
+}
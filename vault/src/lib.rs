@@ -0,0 +1,177 @@
+use crossterm::{
+    cursor,
+    style::{style, Color, Print, PrintStyledContent, StyledContent},
+    terminal, ExecutableCommand,
+};
+use grid::{DenseGrid, Pos};
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::stdout;
+
+pub type Map = DenseGrid<Tile>;
+pub type Positions = Vec<(char, Pos)>;
+pub type Vault = (Map, Vec<Pos>, Positions, Positions);
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Tile {
+    Wall,
+    Ground,
+    Key(char),
+    Door(char),
+}
+
+impl Tile {
+    fn to_styled_char(self) -> StyledContent<char> {
+        match self {
+            Tile::Wall => style('#').with(Color::Grey),
+            Tile::Ground => style('.').with(Color::DarkGrey),
+            Tile::Key(ch) => style(ch).with(Color::Blue),
+            Tile::Door(ch) => style(ch).with(Color::Red),
+        }
+    }
+}
+
+pub fn parse_map(input: &str) -> Result<Vault, Box<dyn Error>> {
+    let chars = input.parse::<DenseGrid<char>>()?;
+
+    let entrances = chars
+        .iter()
+        .filter(|(_, ch)| **ch == '@')
+        .map(|(pos, _)| pos)
+        .collect();
+
+    let keys = chars
+        .iter()
+        .filter(|(_, ch)| ch.is_ascii_lowercase())
+        .map(|(pos, ch)| (ch.to_ascii_uppercase(), pos))
+        .collect();
+    let doors = chars
+        .iter()
+        .filter(|(_, ch)| ch.is_ascii_uppercase())
+        .map(|(pos, ch)| (*ch, pos))
+        .collect();
+
+    let map = chars.map(|ch| match ch {
+        '#' => Tile::Wall,
+        ch if ch.is_ascii_lowercase() => Tile::Key(ch.to_ascii_uppercase()),
+        ch if ch.is_ascii_uppercase() => Tile::Door(*ch),
+        _ => Tile::Ground,
+    });
+
+    Ok((map, entrances, keys, doors))
+}
+
+pub type KeySet = u32;
+
+pub fn key_bit(key: char) -> KeySet {
+    1 << (key as u8 - b'A')
+}
+
+pub fn all_keys(keys: &[(char, Pos)]) -> KeySet {
+    keys.iter()
+        .fold(0, |all_keys, (key, _)| all_keys | key_bit(*key))
+}
+
+#[derive(Copy, Clone)]
+pub struct Path {
+    pub key: char,
+    pub end_pos: Pos,
+    pub len: i32,
+    pub required_keys: KeySet,
+}
+
+impl Path {
+    pub fn is_open(&self, aquired_keys: KeySet) -> bool {
+        aquired_keys & key_bit(self.key) == 0
+            && aquired_keys & self.required_keys == self.required_keys
+    }
+}
+
+fn neighbors(pos: Pos, map: &Map) -> Vec<Pos> {
+    map.neighbors4(pos)
+        .filter(|(_, tile)| **tile != Tile::Wall)
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+fn calculate_paths_to_keys(map: &Map, start_pos: Pos, keys: &[(char, Pos)]) -> Vec<Path> {
+    let search = search::bfs(vec![start_pos], |pos| neighbors(*pos, map));
+
+    keys.iter()
+        .filter(|(_, key_pos)| *key_pos != start_pos)
+        .filter_map(|(key, key_pos)| {
+            let path = search.path_to(key_pos)?;
+
+            Some(Path {
+                key: *key,
+                end_pos: *key_pos,
+                len: path.len() as i32 - 1,
+                required_keys: path
+                    .iter()
+                    .filter_map(|pos| match map.get(*pos) {
+                        Some(Tile::Door(door)) => Some(key_bit(*door)),
+                        _ => None,
+                    })
+                    .fold(0, |required_keys, door| required_keys | door),
+            })
+        })
+        .collect()
+}
+
+pub fn calculate_key_graph(
+    map: &Map,
+    entrances: &[Pos],
+    keys: &[(char, Pos)],
+) -> HashMap<Pos, Vec<Path>> {
+    keys.iter()
+        .map(|(_, key_pos)| *key_pos)
+        .chain(entrances.iter().copied())
+        .map(|start_pos| (start_pos, calculate_paths_to_keys(map, start_pos, keys)))
+        .collect()
+}
+
+pub fn render(map: &Map) -> Result<(), Box<dyn Error>> {
+    stdout().execute(terminal::Clear(terminal::ClearType::All))?;
+
+    for (pos, tile) in map.iter() {
+        stdout()
+            .execute(cursor::MoveTo(pos.0 as u16, pos.1 as u16))?
+            .execute(PrintStyledContent(tile.to_styled_char()))?;
+    }
+
+    let max_pos = map.bounds().max;
+    stdout()
+        .execute(cursor::MoveTo(max_pos.0 as u16, max_pos.1 as u16))?
+        .execute(Print('\n'))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{all_keys, calculate_key_graph, parse_map};
+
+    #[test]
+    fn test_key_graph() {
+        let input = "#########
+#b.A.@.a#
+#########";
+
+        let (map, entrances, keys, doors) = parse_map(input).unwrap();
+        assert_eq!(entrances, [(5, 1)]);
+        assert_eq!(keys, [('B', (1, 1)), ('A', (7, 1))]);
+        assert_eq!(doors, [('A', (3, 1))]);
+        assert_eq!(all_keys(&keys), 0b11);
+
+        let graph = calculate_key_graph(&map, &entrances, &keys);
+        assert_eq!(graph.len(), 3);
+
+        let paths = &graph[&(5, 1)];
+        assert_eq!(paths.iter().filter(|path| path.is_open(0)).count(), 1);
+        assert_eq!(paths.iter().filter(|path| path.is_open(0b01)).count(), 1);
+        assert_eq!(
+            paths.iter().map(|path| path.len).collect::<Vec<_>>(),
+            [4, 2]
+        );
+    }
+}